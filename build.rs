@@ -0,0 +1,67 @@
+//! Generates one regression test per day, part and `test*.txt` input that has an
+//! expected answer in `data/<year>/<day>/answers.toml`, see `tests/regression.rs`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=data");
+    let mut tests = String::new();
+    for year_dir in sorted_entries(Path::new("data")) {
+        let Some(year) = file_name(&year_dir).parse::<u16>().ok() else {
+            continue;
+        };
+        for day_dir in sorted_entries(&year_dir) {
+            let day = file_name(&day_dir);
+            let Ok(answers) = fs::read_to_string(day_dir.join("answers.toml")) else {
+                continue;
+            };
+            let answers = answers.parse::<toml::Table>().unwrap_or_else(|err| {
+                panic!("invalid {}/answers.toml: {}", day_dir.display(), err)
+            });
+            for input in sorted_entries(&day_dir) {
+                let name = file_name(&input);
+                let Some(variant) = name
+                    .strip_suffix(".txt")
+                    .filter(|stem| stem.starts_with("test"))
+                else {
+                    continue;
+                };
+                let Some(section) = answers.get(variant).and_then(toml::Value::as_table) else {
+                    continue;
+                };
+                for part in [1, 2] {
+                    if section.contains_key(&format!("part_{}", part)) {
+                        let test_name = format!("y{}_{}_part_{}_{}", year, day, part, variant)
+                            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+                        writeln!(
+                            tests,
+                            "#[test]\nfn {}() {{\n    check({}, {:?}, {}, {:?});\n}}\n",
+                            test_name, year, day, part, variant
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        }
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("regression_tests.rs");
+    fs::write(out, tests).unwrap();
+}
+
+fn sorted_entries(dir: &Path) -> Vec<std::path::PathBuf> {
+    let mut entries = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect()
+        })
+        .unwrap_or_else(|_| Vec::new());
+    entries.sort();
+    entries
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
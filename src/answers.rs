@@ -0,0 +1,172 @@
+use anyhow::{Context as _, Result, anyhow, bail};
+use std::{fs, path::PathBuf};
+
+use crate::input::{InputSource, day_dir};
+use crate::registry;
+use crate::runner::{self, Task};
+use crate::solution::{Day, Part};
+
+/// Expected answers of a day stored in `data/<year>/<day>/answers.toml`:
+///
+/// ```toml
+/// [input]
+/// part_1 = 42
+///
+/// [test]
+/// part_1 = 7
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(toml::Table);
+
+impl Answers {
+    /// Loads the answers of a day, a missing file is treated as having no answers.
+    pub fn load(day: &Day) -> Result<Self> {
+        let path = answers_path(day);
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        let table = content
+            .parse::<toml::Table>()
+            .with_context(|| format!("parsing {}", path.display()))?;
+        Ok(Answers(table))
+    }
+
+    pub fn expected(&self, input: &str, part: Part) -> Result<Option<String>> {
+        let Some(section) = self.0.get(input) else {
+            return Ok(None);
+        };
+        let section = section
+            .as_table()
+            .ok_or_else(|| anyhow!("[{}] is not a table", input))?;
+        match section.get(&part_key(part)) {
+            Some(toml::Value::String(answer)) => Ok(Some(answer.clone())),
+            Some(toml::Value::Integer(answer)) => Ok(Some(answer.to_string())),
+            Some(value) => bail!(
+                "{}.{} is neither a string nor an integer: {}",
+                input,
+                part_key(part),
+                value
+            ),
+            None => Ok(None),
+        }
+    }
+
+    /// Names of all inputs that have an answer section.
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+pub fn answers_path(day: &Day) -> PathBuf {
+    day_dir(day).join("answers.toml")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part_{}", part)
+}
+
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(anyhow::Error),
+    Missing(String),
+}
+
+pub struct Check {
+    pub day: &'static str,
+    pub input: String,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+/// The inputs checked for a part: the puzzle input, the day's default test input and
+/// every other input listed in the answers file.
+fn inputs_to_check(day: &Day, part: Part, answers: &Answers) -> Result<Vec<String>> {
+    let mut inputs = vec!["input".to_string(), day.test_input(part).to_string()];
+    for input in answers.inputs() {
+        if !inputs.iter().any(|known| known == input) && answers.expected(input, part)?.is_some() {
+            inputs.push(input.to_string());
+        }
+    }
+    Ok(inputs)
+}
+
+fn verify_days(year: u16, jobs: usize) -> Result<Vec<Check>> {
+    let mut tasks = Vec::new();
+    let mut expected = Vec::new();
+    for day in registry::days(year)? {
+        let answers = Answers::load(day)?;
+        for &part in day.parts() {
+            for input in inputs_to_check(day, part, &answers)? {
+                let source = match input.as_str() {
+                    "input" => InputSource::Puzzle,
+                    name => InputSource::Test(Some(name.to_string())),
+                };
+                expected.push((answers.expected(&input, part)?, input));
+                tasks.push(Task { day, part, source });
+            }
+        }
+    }
+    let results = runner::run_many(&tasks, &[], jobs);
+    Ok(results
+        .into_iter()
+        .zip(expected)
+        .map(|(result, (expected, input))| {
+            let outcome = match (result.answer, expected) {
+                (Err(err), _) => Outcome::Error(err),
+                (Ok(answer), None) => Outcome::Missing(answer.value.to_string()),
+                (Ok(answer), Some(expected)) if answer.value.to_string() == expected => {
+                    Outcome::Pass
+                }
+                (Ok(answer), Some(expected)) => Outcome::Fail {
+                    expected,
+                    actual: answer.value.to_string(),
+                },
+            };
+            Check {
+                day: result.day,
+                input,
+                part: result.part,
+                outcome,
+            }
+        })
+        .collect())
+}
+
+/// Verifies all days registered for the year and prints a report. Returns the number of
+/// failed checks.
+pub fn verify_all(year: u16, jobs: usize) -> Result<usize> {
+    let checks = verify_days(year, jobs)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for check in &checks {
+        let label = format!("{} part {} [{}]", check.day, check.part, check.input);
+        match &check.outcome {
+            Outcome::Pass => {
+                passed += 1;
+                println!("PASS    {}", label);
+            }
+            Outcome::Fail { expected, actual } => {
+                failed += 1;
+                println!("FAIL    {}", label);
+                println!("        - {}", expected);
+                println!("        + {}", actual);
+            }
+            Outcome::Error(err) => {
+                failed += 1;
+                println!("FAIL    {}", label);
+                println!("        error: {:#}", err);
+            }
+            Outcome::Missing(actual) => {
+                missing += 1;
+                println!("MISSING {}", label);
+                println!("        got: {}", actual);
+            }
+        }
+    }
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    Ok(failed)
+}
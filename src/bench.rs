@@ -0,0 +1,77 @@
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+use crate::input::InputSource;
+use crate::solution::{Context, Day, Part};
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        let percentile = |p: usize| samples[((samples.len() - 1) * p).div_ceil(100)];
+        Stats {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+}
+
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        f()?;
+    }
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f()?;
+            Ok(start.elapsed())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Stats::from_samples(samples))
+}
+
+/// Benchmarks parsing and solving of the given parts separately and prints the statistics.
+pub fn bench(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+    params: &[(String, String)],
+    config: &BenchConfig,
+) -> Result<()> {
+    println!(
+        "{:<14} {:>12} {:>12} {:>12} {:>12}",
+        day.name, "min", "median", "p95", "mean"
+    );
+    for &part in parts {
+        let ctx = Context::new(day.params, source.name(day, part)?.as_deref(), params)?;
+        let path = source.resolve(day, part)?;
+        let parse = measure(config, || day.parse(&path))?;
+        let input = day.parse(&path)?;
+        let solve = measure(config, || day.solve(input.as_ref(), part, &ctx))?;
+        for (phase, stats) in [("parse", parse), ("solve", solve)] {
+            println!(
+                "{:<14} {:>12} {:>12} {:>12} {:>12}",
+                format!("part {} {}", part, phase),
+                format!("{:.3?}", stats.min),
+                format!("{:.3?}", stats.median),
+                format!("{:.3?}", stats.p95),
+                format!("{:.3?}", stats.mean),
+            );
+        }
+    }
+    Ok(())
+}
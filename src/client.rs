@@ -0,0 +1,274 @@
+use anyhow::{Context as _, Result, anyhow};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/simonplhak/advent-of-code-2025";
+const DEFAULT_SESSION_FILE: &str = ".aoc-session";
+
+/// HTTP client of the puzzle website. The base URL is configurable so the client can
+/// be pointed at a local server in tests.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// Configures the client from the arguments, falling back to the `AOC_BASE_URL`,
+    /// `AOC_SESSION` and `AOC_SESSION_FILE` variables and the `.aoc-session` file.
+    pub fn from_env(base_url: Option<String>, session_file: Option<PathBuf>) -> Result<Self> {
+        let base_url = base_url
+            .or_else(|| env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => Some(session),
+            Err(_) => {
+                let path = session_file
+                    .or_else(|| env::var_os("AOC_SESSION_FILE").map(PathBuf::from))
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_SESSION_FILE));
+                match fs::read_to_string(&path) {
+                    Ok(session) => Some(session),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+                    Err(err) => {
+                        return Err(err).with_context(|| format!("reading {}", path.display()));
+                    }
+                }
+            }
+        };
+        let session = session
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        Ok(Client::new(&base_url, session))
+    }
+
+    fn cookie(&self) -> Result<String> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            anyhow!("no session token, set AOC_SESSION or write it to {DEFAULT_SESSION_FILE}")
+        })?;
+        Ok(format!("session={}", session))
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        let body = ureq::get(&url)
+            .header("Cookie", self.cookie()?)
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("GET {}", url))?
+            .body_mut()
+            .read_to_string()?;
+        Ok(body)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = self.url(path);
+        let body = ureq::post(&url)
+            .header("Cookie", self.cookie()?)
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .with_context(|| format!("POST {}", url))?
+            .body_mut()
+            .read_to_string()?;
+        Ok(body)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FetchOutcome {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the puzzle input into `cache_path` unless a non-empty file is already there.
+pub fn fetch_input(
+    client: &Client,
+    year: u16,
+    day: usize,
+    cache_path: &Path,
+) -> Result<FetchOutcome> {
+    if fs::metadata(cache_path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(FetchOutcome::Cached);
+    }
+    let input = client.get(&format!("{}/day/{}/input", year, day))?;
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(cache_path, input)?;
+    Ok(FetchOutcome::Downloaded)
+}
+
+/// Minimal HTTP server answering requests with canned responses, used instead of the
+/// real website in tests.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    pub struct MockServer {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        /// Serves the given `(status, body)` responses in order, one per connection.
+        pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = requests.clone();
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let mut parts = line.split_whitespace();
+                    let method = parts.next().unwrap_or_default().to_string();
+                    let path = parts.next().unwrap_or_default().to_string();
+                    let mut headers = Vec::new();
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        let line = line.trim_end();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some((key, value)) = line.split_once(':') {
+                            headers.push((key.trim().to_string(), value.trim().to_string()));
+                        }
+                    }
+                    let length = headers
+                        .iter()
+                        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                        .map_or(0, |(_, value)| value.parse().unwrap());
+                    let mut body_bytes = vec![0; length];
+                    reader.read_exact(&mut body_bytes).unwrap();
+                    recorded.lock().unwrap().push(Request {
+                        method,
+                        path,
+                        headers,
+                        body: String::from_utf8(body_bytes).unwrap(),
+                    });
+                    let mut stream = reader.into_inner();
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+            MockServer { base_url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockServer;
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("aoc-client-{}-{}", std::process::id(), name))
+            .join("input.txt")
+    }
+
+    #[test]
+    fn test_fetch_downloads_and_caches() {
+        let server = MockServer::start(vec![(200, "1,2,3\n")]);
+        let client = Client::new(&server.base_url, Some("secret".to_string()));
+        let path = temp_path("download");
+
+        assert_eq!(
+            fetch_input(&client, 2025, 8, &path).unwrap(),
+            FetchOutcome::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+        // The server only answers once, a second download would fail.
+        assert_eq!(
+            fetch_input(&client, 2025, 8, &path).unwrap(),
+            FetchOutcome::Cached
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/8/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_does_not_write_cache() {
+        let server = MockServer::start(vec![(404, "not found")]);
+        let client = Client::new(&server.base_url, Some("secret".to_string()));
+        let path = temp_path("missing");
+
+        assert!(fetch_input(&client, 2025, 13, &path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_fetch_requires_session() {
+        let client = Client::new("http://127.0.0.1:1", None);
+        let err = fetch_input(&client, 2025, 1, &temp_path("no-session")).unwrap_err();
+        assert!(err.to_string().contains("no session token"));
+    }
+
+    #[test]
+    fn test_post_form() {
+        let server = MockServer::start(vec![(200, "ok")]);
+        let client = Client::new(&server.base_url, Some("secret".to_string()));
+
+        assert_eq!(
+            client
+                .post_form("2025/day/1/answer", &[("level", "1"), ("answer", "42")])
+                .unwrap(),
+            "ok"
+        );
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, "level=1&answer=42");
+    }
+}
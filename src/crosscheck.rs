@@ -0,0 +1,237 @@
+use anyhow::{Result, anyhow, bail};
+use std::{
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+};
+use tracing::debug;
+
+use crate::runner::panic_message;
+use crate::solution::{Context, Day, Part, Value};
+
+/// Input name selecting the knob and parameter defaults used by `crosscheck`. The
+/// reference implementations are slow, so the days keep these inputs small.
+pub const PROFILE: &str = "crosscheck";
+
+/// Answer value of a run or its error, panics included.
+pub type Outcome = Result<Value, String>;
+
+pub struct CrosscheckConfig {
+    pub seed: u64,
+    pub iterations: usize,
+}
+
+/// First generated input on which the solution and the reference disagree.
+pub struct Mismatch {
+    pub seed: u64,
+    pub part: Part,
+    pub generated: String,
+    /// Smallest input found that still disagrees, made of whole lines of `generated`
+    /// (or comma separated items when it is a single line).
+    pub minimized: String,
+    pub solution: Outcome,
+    pub reference: Outcome,
+}
+
+struct Checker<'a> {
+    day: &'a Day,
+    ctx: Context,
+    path: PathBuf,
+}
+
+impl Checker<'_> {
+    /// Outcomes of both implementations when they disagree. Inputs the day cannot parse
+    /// and inputs both implementations reject are not mismatches.
+    fn compare(&self, content: &str, part: Part) -> Option<(Outcome, Outcome)> {
+        fs::write(&self.path, content).ok()?;
+        let input = catch(|| self.day.parse(&self.path)).ok()?;
+        let solution = catch(|| {
+            self.day
+                .solve(input.as_ref(), part, &self.ctx)
+                .map(|a| a.value)
+        });
+        let reference = catch(|| {
+            self.day
+                .solve_reference(input.as_ref(), part, &self.ctx)
+                .map(|a| a.value)
+        });
+        match (&solution, &reference) {
+            (Ok(a), Ok(b)) if a == b => None,
+            (Err(_), Err(_)) => None,
+            _ => Some((solution, reference)),
+        }
+    }
+}
+
+fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(anyhow!("panicked: {}", panic_message(&payload))))
+        .map_err(|err| format!("{:#}", err))
+}
+
+/// Runs the solution and the reference implementation of the day on generated inputs
+/// for consecutive seeds and returns the first disagreement, minimized.
+pub fn crosscheck(
+    day: &Day,
+    config: &CrosscheckConfig,
+    knobs: &[(String, String)],
+    params: &[(String, String)],
+) -> Result<Option<Mismatch>> {
+    if !day.has_reference {
+        bail!("{} has no reference implementation", day.name);
+    }
+    let checker = Checker {
+        day,
+        ctx: Context::new(day.params, Some(PROFILE), params)?,
+        path: env::temp_dir().join(format!(
+            "aoc-crosscheck-{}-{}-{}.txt",
+            std::process::id(),
+            day.year,
+            day.name
+        )),
+    };
+    // Panics are expected here and reported as outcomes, keep them off stderr.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = find_mismatch(&checker, config, knobs);
+    panic::set_hook(hook);
+    let _ = fs::remove_file(&checker.path);
+    result
+}
+
+fn find_mismatch(
+    checker: &Checker,
+    config: &CrosscheckConfig,
+    knobs: &[(String, String)],
+) -> Result<Option<Mismatch>> {
+    for seed in config.seed..config.seed + config.iterations as u64 {
+        let generated = checker.day.generate(seed, Some(PROFILE), knobs)?;
+        for &part in checker.day.parts() {
+            if checker.compare(&generated, part).is_none() {
+                continue;
+            }
+            debug!(seed, part = part.number(), "mismatch, minimizing");
+            let minimized = minimize(&generated, |content| {
+                checker.compare(content, part).is_some()
+            });
+            let (solution, reference) = checker
+                .compare(&minimized, part)
+                .expect("minimized input still disagrees");
+            return Ok(Some(Mismatch {
+                seed,
+                part,
+                generated,
+                minimized,
+                solution,
+                reference,
+            }));
+        }
+        debug!(seed, "agree");
+    }
+    Ok(None)
+}
+
+/// Removes chunks of lines, halving the chunk size whenever no chunk can be removed,
+/// as long as `fails` holds for what is left. Single line inputs are split on commas.
+pub fn minimize(content: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let lines = content.lines().collect::<Vec<_>>();
+    let (mut units, separator) = match lines.len() {
+        1 => (lines[0].split(',').collect::<Vec<_>>(), ","),
+        _ => (lines, "\n"),
+    };
+    let join = |units: &[&str]| units.join(separator) + "\n";
+    let mut chunk = (units.len() / 2).max(1);
+    while chunk >= 1 && units.len() > 1 {
+        let mut removed = false;
+        let mut start = 0;
+        while start < units.len() && units.len() > 1 {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed {
+            chunk /= 2;
+        }
+    }
+    let minimized = join(&units);
+    match fails(&minimized) {
+        true => minimized,
+        false => content.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::solution::{Answer, Solution};
+    use crate::utils::read_lines;
+    use std::path::Path;
+
+    /// Sums numbers, but the solution drops every 7.
+    struct Sevens;
+
+    impl Solution for Sevens {
+        type Input = Vec<usize>;
+
+        const HAS_PART_TWO: bool = false;
+
+        fn parse(data_path: &Path) -> Result<Self::Input> {
+            Ok(read_lines(data_path)?
+                .iter()
+                .map(|line| line.parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(input: &Self::Input, _ctx: &Context) -> Result<Answer> {
+            Ok(Answer::new(
+                input.iter().filter(|&&n| n != 7).sum::<usize>(),
+            ))
+        }
+
+        const HAS_GENERATOR: bool = true;
+
+        fn generate(rng: &mut Rng, _knobs: &Context) -> Result<String> {
+            Ok((0..10).map(|_| format!("{}\n", rng.range(1..=9))).collect())
+        }
+
+        const HAS_REFERENCE: bool = true;
+
+        fn reference(input: &Self::Input, _part: Part, _ctx: &Context) -> Result<Answer> {
+            Ok(Answer::new(input.iter().sum::<usize>()))
+        }
+    }
+
+    #[test]
+    fn test_crosscheck_minimizes_mismatch() {
+        let day = Day::new::<Sevens>(2025, "sevens");
+        let config = CrosscheckConfig {
+            seed: 0,
+            iterations: 10,
+        };
+        let mismatch = crosscheck(&day, &config, &[], &[]).unwrap().unwrap();
+        assert!(mismatch.generated.lines().any(|line| line == "7"));
+        assert_eq!(mismatch.minimized, "7\n");
+        assert_eq!(mismatch.solution, Ok(Value::Number(0)));
+        assert_eq!(mismatch.reference, Ok(Value::Number(7)));
+    }
+
+    #[test]
+    fn test_minimize_lines() {
+        let content = (1..=20).map(|i| format!("{}\n", i)).collect::<String>();
+        let fails = |s: &str| s.lines().any(|l| l == "3") && s.lines().any(|l| l == "17");
+        assert_eq!(minimize(&content, fails), "3\n17\n");
+    }
+
+    #[test]
+    fn test_minimize_single_line() {
+        let fails = |s: &str| s.contains("5-9");
+        assert_eq!(minimize("1-2,5-9,11-20\n", fails), "5-9\n");
+        assert_eq!(minimize("5-9\n", fails), "5-9\n");
+    }
+}
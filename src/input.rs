@@ -0,0 +1,116 @@
+use anyhow::{Context as _, Result, anyhow};
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+use tempfile::NamedTempFile;
+
+use crate::solution::{Day, Part};
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// `data/<year>/<day>/input.txt`
+    Puzzle,
+    /// One of the `data/<year>/<day>/test*.txt` files. Without a name the day's default test
+    /// file for the given part is used.
+    Test(Option<String>),
+    /// An arbitrary file given on the command line.
+    File(PathBuf),
+    /// Standard input, read up front and shared by all parts. Solutions parse from a path,
+    /// so it is kept in a temporary file that is removed with the last clone.
+    Stdin(Arc<NamedTempFile>),
+}
+
+impl InputSource {
+    /// Reads stdin right away when the input is `-`.
+    pub fn from_args(input: Option<PathBuf>, test: Option<Option<String>>) -> Result<Self> {
+        Ok(match (input, test) {
+            (Some(path), _) if path.as_os_str() == "-" => {
+                InputSource::Stdin(Arc::new(read_stdin()?))
+            }
+            (Some(path), _) => InputSource::File(path),
+            (None, Some(name)) => InputSource::Test(name),
+            (None, None) => InputSource::Puzzle,
+        })
+    }
+
+    /// Name of the data file used for the given part, `None` for inputs outside of `data/`.
+    pub fn name(&self, day: &Day, part: Part) -> Result<Option<String>> {
+        match self {
+            InputSource::Puzzle => Ok(Some("input".to_string())),
+            InputSource::Test(None) => Ok(Some(day.test_input(part).to_string())),
+            InputSource::Test(Some(name)) => {
+                let variants = test_variants(day)?;
+                let variant = variants
+                    .into_iter()
+                    .find(|variant| {
+                        variant == name
+                            || *variant == format!("test-{}", name)
+                            || *variant == format!("test{}", name)
+                    })
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "{} has no test input named '{}', available: {}",
+                            day.name,
+                            name,
+                            test_variants(day).unwrap_or_default().join(", ")
+                        )
+                    })?;
+                Ok(Some(variant))
+            }
+            InputSource::File(_) | InputSource::Stdin(_) => Ok(None),
+        }
+    }
+
+    pub fn resolve(&self, day: &Day, part: Part) -> Result<PathBuf> {
+        match self {
+            InputSource::File(path) => Ok(path.clone()),
+            InputSource::Stdin(file) => Ok(file.path().to_path_buf()),
+            _ => {
+                let name = self.name(day, part)?.expect("data inputs are named");
+                Ok(day_dir(day).join(format!("{}.txt", name)))
+            }
+        }
+    }
+}
+
+pub fn day_dir(day: &Day) -> PathBuf {
+    data_dir(day.year, day.name)
+}
+
+/// Data directory of a day, `data/<year>/<day>`, whether or not the day is registered.
+pub fn data_dir(year: u16, name: &str) -> PathBuf {
+    PathBuf::from("data").join(year.to_string()).join(name)
+}
+
+/// Names (file stems) of all `test*.txt` files of a day, sorted.
+pub fn test_variants(day: &Day) -> Result<Vec<String>> {
+    let dir = day_dir(day);
+    let mut variants = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt")
+            && let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+            && stem.starts_with("test")
+        {
+            variants.push(stem.to_string());
+        }
+    }
+    variants.sort();
+    Ok(variants)
+}
+
+/// Copies stdin into a new temporary file, created exclusively under a random name.
+fn read_stdin() -> Result<NamedTempFile> {
+    let mut file = tempfile::Builder::new()
+        .prefix("aoc-stdin-")
+        .suffix(".txt")
+        .tempfile()
+        .context("creating a temporary file for stdin")?;
+    io::copy(&mut io::stdin().lock(), &mut file)?;
+    file.flush()?;
+    Ok(file)
+}
@@ -0,0 +1,18 @@
+//! Advent of Code solutions, grouped per year in `y<year>` modules, together with the
+//! harness used to run, verify and benchmark them. The `advent-of-code-2025` binary is a thin CLI on top of this crate.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod crosscheck;
+pub mod input;
+pub mod mem;
+pub mod output;
+pub mod parse;
+pub mod registry;
+pub mod rng;
+pub mod runner;
+pub mod solution;
+pub mod submit;
+pub mod utils;
+pub mod y2025;
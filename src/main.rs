@@ -1,20 +1,21 @@
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod utils;
-use std::{fmt::Display, path::PathBuf};
-
-use anyhow::Result;
-use clap::{Parser, Subcommand};
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow, bail};
+use clap::{Args, Parser, Subcommand};
+use tracing::Level;
+
+use advent_of_code_2025::input::{self, InputSource};
+use advent_of_code_2025::mem::{self, CountingAllocator};
+use advent_of_code_2025::output::{self, Format};
+use advent_of_code_2025::solution::{Day, Part};
+use advent_of_code_2025::{answers, bench, client, crosscheck, registry, runner, submit};
+
+mod scaffold;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 #[derive(Parser, Debug)]
 #[command(name = "cli_app", version = "1.0", about = "", long_about = None)]
@@ -22,126 +23,366 @@ struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     second: bool,
 
-    #[arg(short, long)]
-    test: bool,
+    /// Use a test input. Without a name the day's default test file is used,
+    /// otherwise the matching `test*.txt` variant, e.g. `--test=02`
+    #[arg(
+        short,
+        long,
+        global = true,
+        num_args = 0..=1,
+        require_equals = true,
+        conflicts_with = "input"
+    )]
+    test: Option<Option<String>>,
+
+    /// Use the named test input, like `--test=<NAME>` but also accepting `--test-name 02`
+    #[arg(long, global = true, value_name = "NAME", conflicts_with_all = ["input", "test"])]
+    test_name: Option<String>,
+
+    /// Read the input from the given file, or from stdin when `-`
+    #[arg(short, long, global = true)]
+    input: Option<PathBuf>,
+
+    /// Output format of the answers
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Set a puzzle parameter declared by the day, e.g. `--param num_connections=20`
+    #[arg(short, long = "param", global = true, value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Re-run the day every time its input file changes
+    #[arg(short, long, global = true)]
+    watch: bool,
+
+    /// Print debug diagnostics to stderr, repeat for trace output
+    #[arg(short, long, global = true, action = clap::ArgAction::Count, conflicts_with = "quiet")]
+    verbose: u8,
+
+    /// Only print errors to stderr
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Report the heap usage of the parse and solve phases, runs the days one at a time
+    #[arg(long, global = true)]
+    mem: bool,
+
+    /// Year of the event whose solutions are used
+    #[arg(short, long, global = true, default_value_t = registry::DEFAULT_YEAR)]
+    year: u16,
+
+    /// Number of days run in parallel by `run-all` and `verify`, defaults to the number of cores
+    #[arg(short, long, global = true)]
+    jobs: Option<usize>,
 }
 
-#[derive(Subcommand, Debug)]
-enum Commands {
-    Day01,
-    Day02,
-    Day03,
-    Day04,
-    Day05,
-    Day06,
-    Day07,
-    Day08,
-    Day09,
-    Day10,
-    Day11,
-    Day12,
+fn find_day(year: u16, name: &str) -> Result<&'static Day> {
+    registry::days(year)?;
+    registry::find(year, name).ok_or_else(|| anyhow!("unknown day for {}: {}", year, name))
 }
 
-impl Display for Commands {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Commands::Day01 => write!(f, "day_01"),
-            Commands::Day02 => write!(f, "day_02"),
-            Commands::Day03 => write!(f, "day_03"),
-            Commands::Day04 => write!(f, "day_04"),
-            Commands::Day05 => write!(f, "day_05"),
-            Commands::Day06 => write!(f, "day_06"),
-            Commands::Day07 => write!(f, "day_07"),
-            Commands::Day08 => write!(f, "day_08"),
-            Commands::Day09 => write!(f, "day_09"),
-            Commands::Day10 => write!(f, "day_10"),
-            Commands::Day11 => write!(f, "day_11"),
-            Commands::Day12 => write!(f, "day_12"),
-        }
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got '{}'", s))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+/// Connection settings of the puzzle website
+#[derive(Args, Debug)]
+struct ClientArgs {
+    /// Base URL of the website, defaults to `AOC_BASE_URL` or https://adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+    /// File with the session cookie, used when `AOC_SESSION` is not set
+    #[arg(long)]
+    session_file: Option<PathBuf>,
+}
+
+impl ClientArgs {
+    fn client(&self) -> Result<client::Client> {
+        client::Client::from_env(self.base_url.clone(), self.session_file.clone())
     }
 }
 
-fn data_path(cli: &Cli) -> PathBuf {
-    PathBuf::from(format!(
-        "data/{}/{}.txt",
-        cli.command,
-        match cli.test {
-            true => "test",
-            false => "input",
-        }
-    ))
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// List all registered days
+    List,
+    /// Run both parts of every registered day and print a summary table
+    RunAll,
+    /// Run every day and compare the answers with `data/<year>/<day>/answers.toml`
+    Verify,
+    /// Create a new day from `src/template.rs` and register it
+    New { day: String },
+    /// Benchmark parsing and solving of a day
+    Bench {
+        day: String,
+        /// Number of untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Number of timed runs
+        #[arg(short = 'n', long, default_value_t = 20)]
+        iterations: usize,
+    },
+    /// Download the puzzle input of a day into `data/<year>/<day>/input.txt` unless already there
+    Fetch {
+        day: String,
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Solve a part on the puzzle input and submit the answer, see `data/<year>/<day>/submissions.toml`
+    Submit {
+        day: String,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Write a random input generated from the seed, by default to
+    /// `data/<year>/<day>/generated-<seed>.txt`
+    Generate {
+        day: String,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Set a size knob declared by the day's generator, e.g. `--knob rows=20`
+        #[arg(short, long = "knob", value_parser = parse_param)]
+        knobs: Vec<(String, String)>,
+        /// Output file, `-` for stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Compare the day with its naive reference implementation on generated inputs and
+    /// print the first disagreeing input, minimized
+    Crosscheck {
+        day: String,
+        /// Seed of the first generated input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Number of generated inputs
+        #[arg(short = 'n', long, default_value_t = 100)]
+        iterations: usize,
+        /// Set a size knob declared by the day's generator, e.g. `--knob rows=20`
+        #[arg(short, long = "knob", value_parser = parse_param)]
+        knobs: Vec<(String, String)>,
+    },
+    /// Run a single day, e.g. `day_01`
+    #[command(external_subcommand)]
+    Day(Vec<String>),
 }
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
-    let res = match &cli.command {
-        Commands::Day01 => match cli.second {
-            true => day_01::task_02(&data_path(&cli))?,
-            false => day_01::task_01(&data_path(&cli))?,
-        },
-        Commands::Day02 => match cli.second {
-            true => day_02::task_02(&data_path(&cli))?,
-            false => day_02::task_01(&data_path(&cli))?,
-        },
-        Commands::Day03 => match cli.second {
-            true => day_03::task_02(&data_path(&cli))?,
-            false => day_03::task_01(&data_path(&cli))?,
-        },
-        Commands::Day04 => match cli.second {
-            true => day_04::task_02(&data_path(&cli))?,
-            false => day_04::task_01(&data_path(&cli))?,
-        },
-        Commands::Day05 => match cli.second {
-            true => day_05::task_02(&data_path(&cli))?,
-            false => day_05::task_01(&data_path(&cli))?,
-        },
-        Commands::Day06 => match cli.second {
-            true => day_06::task_02(&data_path(&cli))?,
-            false => day_06::task_01(&data_path(&cli))?,
-        },
-        Commands::Day07 => match cli.second {
-            true => day_07::task_02(&data_path(&cli))?,
-            false => day_07::task_01(&data_path(&cli))?,
-        },
-        Commands::Day08 => match cli.second {
-            true => day_08::task_02(&data_path(&cli))?,
-            false => day_08::task_01(
-                &data_path(&cli),
-                match &cli.test {
-                    true => 10,
-                    false => 1000,
-                },
-            )?,
-        },
-        Commands::Day09 => match cli.second {
-            true => day_09::task_02(&data_path(&cli))?,
-            false => day_09::task_01(&data_path(&cli))?,
-        },
-        Commands::Day10 => match cli.second {
-            true => day_10::task_02(&data_path(&cli))?,
-            false => day_10::task_01(&data_path(&cli))?,
-        },
-        Commands::Day11 => match cli.second {
-            true => {
-                let data_path = match &cli.test {
-                    true => PathBuf::from("data/day_11/test-02.txt"),
-                    false => PathBuf::from("data/day_11/input.txt"),
-                };
-                day_11::task_02(&data_path)?
+    let mut cli = Cli::parse();
+    if let Some(name) = cli.test_name.take() {
+        cli.test = Some(Some(name));
+    }
+    let level = match (cli.quiet, cli.verbose) {
+        (true, _) => Level::ERROR,
+        (false, 0) => Level::INFO,
+        (false, 1) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .init();
+    // The allocation counters are process wide, so measured runs must not overlap.
+    let jobs = match cli.mem {
+        true => 1,
+        false => cli.jobs.unwrap_or_else(runner::default_jobs),
+    };
+    if cli.mem {
+        mem::enable();
+    }
+    match &cli.command {
+        Commands::List => {
+            for day in registry::days(cli.year)? {
+                let variants = input::test_variants(day).unwrap_or_default();
+                println!(
+                    "{} (parts: {}, tests: {})",
+                    day.name,
+                    day.parts().len(),
+                    variants.join(", ")
+                );
+                for param in day.params {
+                    println!("    --param {}={}", param.name, param.default);
+                    for (input, value) in param.input_defaults {
+                        println!("        ({}: {})", input, value);
+                    }
+                }
+                if day.has_generator {
+                    let knobs = day
+                        .knobs
+                        .iter()
+                        .map(|knob| format!("{}={}", knob.name, knob.default))
+                        .collect::<Vec<_>>();
+                    println!("    generate --knob {}", knobs.join(" --knob "));
+                }
+            }
+        }
+        Commands::RunAll => {
+            let source = InputSource::from_args(cli.input, cli.test)?;
+            let days = registry::days(cli.year)?;
+            if let Some((name, _)) = cli.params.iter().find(|(name, _)| {
+                !days
+                    .iter()
+                    .any(|day| day.params.iter().any(|param| param.name == name))
+            }) {
+                bail!("no day declares the parameter '{}'", name);
             }
-            false => {
-                let data_path = match &cli.test {
-                    true => PathBuf::from("data/day_11/test-01.txt"),
-                    false => PathBuf::from("data/day_11/input.txt"),
-                };
-                day_11::task_01(&data_path)?
+            let tasks = days
+                .iter()
+                .flat_map(|day| day.parts().iter().map(move |part| (day, *part)))
+                .map(|(day, part)| runner::Task {
+                    day,
+                    part,
+                    source: source.clone(),
+                })
+                .collect::<Vec<_>>();
+            let results = runner::run_many(&tasks, &cli.params, jobs);
+            output::print_results(&results, cli.format);
+            let failed = results.iter().filter(|res| res.answer.is_err()).count();
+            if failed > 0 {
+                bail!("{} of {} runs failed", failed, results.len());
             }
-        },
-        Commands::Day12 => day_12::task_01(&data_path(&cli))?,
-    };
-    println!("{:?}: {}", cli.command, res);
+        }
+        Commands::Verify => {
+            let failed = answers::verify_all(cli.year, jobs)?;
+            if failed > 0 {
+                bail!("{} checks failed", failed);
+            }
+        }
+        Commands::New { day } => {
+            let name = scaffold::new_day(cli.year, day)?;
+            println!(
+                "Created src/y{}/{}.rs and data/{}/{}/",
+                cli.year, name, cli.year, name
+            );
+        }
+        Commands::Bench {
+            day,
+            warmup,
+            iterations,
+        } => {
+            let day = find_day(cli.year, day)?;
+            let source = InputSource::from_args(cli.input, cli.test)?;
+            let config = bench::BenchConfig {
+                warmup: *warmup,
+                iterations: *iterations,
+            };
+            bench::bench(day, day.parts(), &source, &cli.params, &config)?;
+        }
+        Commands::Fetch { day, client } => {
+            let number =
+                registry::day_number(day).ok_or_else(|| anyhow!("invalid day: {}", day))?;
+            let path = input::data_dir(cli.year, &registry::day_name(number)).join("input.txt");
+            match client::fetch_input(&client.client()?, cli.year, number, &path)? {
+                client::FetchOutcome::Cached => println!("{} already exists", path.display()),
+                client::FetchOutcome::Downloaded => println!("Downloaded {}", path.display()),
+            }
+        }
+        Commands::Submit { day, part, client } => {
+            let day = find_day(cli.year, day)?;
+            let part = match part {
+                1 => Part::One,
+                _ => Part::Two,
+            };
+            let answer = runner::run(day, part, &InputSource::Puzzle, &cli.params).answer?;
+            let answer = answer.value.to_string();
+            let submission = submit::Submission {
+                year: cli.year,
+                day: registry::day_number(day.name).expect("registered days are numbered"),
+                part,
+                answer: &answer,
+            };
+            let history = submit::history_path(&input::day_dir(day));
+            let verdict = submit::submit(&client.client()?, &submission, &history, submit::now())?;
+            println!("{} part {}: {} is {}", day.name, part, answer, verdict);
+        }
+        Commands::Generate {
+            day,
+            seed,
+            knobs,
+            output,
+        } => {
+            let day = find_day(cli.year, day)?;
+            let content = day.generate(*seed, None, knobs)?;
+            let path = output
+                .clone()
+                .unwrap_or_else(|| input::day_dir(day).join(format!("generated-{}.txt", seed)));
+            if path.as_os_str() == "-" {
+                print!("{}", content);
+            } else {
+                std::fs::write(&path, content)?;
+                println!("Generated {}", path.display());
+            }
+        }
+        Commands::Crosscheck {
+            day,
+            seed,
+            iterations,
+            knobs,
+        } => {
+            let day = find_day(cli.year, day)?;
+            let config = crosscheck::CrosscheckConfig {
+                seed: *seed,
+                iterations: *iterations,
+            };
+            let Some(mismatch) = crosscheck::crosscheck(day, &config, knobs, &cli.params)? else {
+                println!(
+                    "{}: solution and reference agree on seeds {}..{}",
+                    day.name,
+                    seed,
+                    seed + *iterations as u64
+                );
+                return Ok(());
+            };
+            let show = |outcome: &crosscheck::Outcome| match outcome {
+                Ok(value) => value.to_string(),
+                Err(err) => format!("error: {}", err),
+            };
+            println!(
+                "{} part {} disagrees on seed {} ({} of {} lines kept):",
+                day.name,
+                mismatch.part,
+                mismatch.seed,
+                mismatch.minimized.lines().count(),
+                mismatch.generated.lines().count()
+            );
+            print!("{}", mismatch.minimized);
+            println!("solution:  {}", show(&mismatch.solution));
+            println!("reference: {}", show(&mismatch.reference));
+            bail!(
+                "{} part {} disagrees with its reference",
+                day.name,
+                mismatch.part
+            );
+        }
+        Commands::Day(args) => {
+            let [name] = args.as_slice() else {
+                bail!("expected a single day name, got: {}", args.join(" "));
+            };
+            let day = find_day(cli.year, name)?;
+            let part = match cli.second {
+                true => Part::Two,
+                false => Part::One,
+            };
+            let source = InputSource::from_args(cli.input, cli.test)?;
+            if cli.watch {
+                return watch::watch(day, part, &source, &cli.params, cli.format);
+            }
+            let result = runner::run(day, part, &source, &cli.params);
+            output::print_result(&result, cli.format);
+            if result.answer.is_err() {
+                bail!("{} part {} failed", day.name, part);
+            }
+        }
+    }
     Ok(())
 }
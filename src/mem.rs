@@ -0,0 +1,159 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator counting the heap usage of the process while counting is enabled.
+/// The binary installs it with `#[global_allocator]`, `--mem` turns the counting on.
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        TOTAL.fetch_add(size, Ordering::Relaxed);
+        COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn record_dealloc(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Heap usage of a measured phase. The counters are process wide, so phases running
+/// in parallel are counted together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemStats {
+    /// Highest heap usage above the usage at the start of the phase.
+    pub peak_bytes: usize,
+    /// Sum of the sizes of all allocations, a reallocation counts as a new allocation.
+    pub total_bytes: usize,
+    pub allocations: usize,
+}
+
+/// Heap usage of the parse and solve phases of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemReport {
+    pub parse: MemStats,
+    pub solve: MemStats,
+}
+
+/// Runs `f` and returns the heap usage recorded while it ran.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemStats) {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let total = TOTAL.load(Ordering::Relaxed);
+    let count = COUNT.load(Ordering::Relaxed);
+    let result = f();
+    let stats = MemStats {
+        peak_bytes: (PEAK.load(Ordering::Relaxed) - base).max(0) as usize,
+        total_bytes: TOTAL.load(Ordering::Relaxed) - total,
+        allocations: COUNT.load(Ordering::Relaxed) - count,
+    };
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, UNITS[unit]),
+    }
+}
+
+impl Display for MemStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, total {} in {} allocations",
+            format_bytes(self.peak_bytes),
+            format_bytes(self.total_bytes),
+            self.allocations
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure_counts_allocations() {
+        enable();
+        let (len, stats) = measure(|| {
+            let small = vec![0u8; 1000];
+            let large = vec![0u64; 1000];
+            small.len() + large.len()
+        });
+        assert_eq!(len, 2000);
+        // Other tests may allocate concurrently, so only lower bounds are checked.
+        assert!(stats.allocations >= 2);
+        assert!(stats.total_bytes >= 9000);
+        assert!(stats.peak_bytes >= 9000);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,226 @@
+use clap::ValueEnum;
+use serde_json::{Map, json};
+
+use crate::mem::{self, MemReport, MemStats};
+use crate::runner::RunResult;
+use crate::solution::{Answer, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn print_results(results: &[RunResult], format: Format) {
+    match format {
+        Format::Text => {
+            print_table(results);
+            if results.iter().any(|res| res.memory.is_some()) {
+                println!();
+                print_memory_table(results);
+            }
+        }
+        Format::Json => {
+            let results = results.iter().map(to_json).collect::<Vec<_>>();
+            println!("{}", serde_json::Value::Array(results));
+        }
+        Format::Csv => print_csv(results),
+    }
+}
+
+/// Prints the result of a single run, the text format keeps the `day: answer` line.
+pub fn print_result(result: &RunResult, format: Format) {
+    match (format, &result.answer) {
+        (Format::Text, answer) => {
+            match answer {
+                Ok(answer) => println!("{}: {}", result.day, answer),
+                Err(err) => println!("{}: error: {:#}", result.day, err),
+            }
+            if let Some(memory) = &result.memory {
+                println!("  parse: {}", memory.parse);
+                println!("  solve: {}", memory.solve);
+            }
+        }
+        (Format::Json, _) => println!("{}", to_json(result)),
+        (Format::Csv, _) => print_csv(std::slice::from_ref(result)),
+    }
+}
+
+fn value_to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Number(n) => json!(n),
+        Value::Text(s) => json!(s),
+    }
+}
+
+fn extras_to_json(answer: &Answer) -> serde_json::Value {
+    let extras = answer
+        .extras
+        .iter()
+        .map(|(name, value)| (name.clone(), value_to_json(value)))
+        .collect::<Map<_, _>>();
+    serde_json::Value::Object(extras)
+}
+
+fn to_json(result: &RunResult) -> serde_json::Value {
+    let mut object = json!({
+        "day": result.day,
+        "part": result.part.number(),
+        "time_us": result.elapsed.as_micros() as u64,
+    });
+    if let Some(memory) = &result.memory {
+        object["memory"] = json!({
+            "parse": mem_to_json(&memory.parse),
+            "solve": mem_to_json(&memory.solve),
+        });
+    }
+    match &result.answer {
+        Ok(answer) => {
+            object["status"] = json!("ok");
+            object["answer"] = value_to_json(&answer.value);
+            object["extras"] = extras_to_json(answer);
+        }
+        Err(err) => {
+            object["status"] = json!("error");
+            object["error"] = json!(format!("{:#}", err));
+        }
+    }
+    object
+}
+
+fn mem_to_json(stats: &MemStats) -> serde_json::Value {
+    json!({
+        "peak_bytes": stats.peak_bytes,
+        "total_bytes": stats.total_bytes,
+        "allocations": stats.allocations,
+    })
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn print_csv(results: &[RunResult]) {
+    let with_memory = results.iter().any(|res| res.memory.is_some());
+    let mut header = "day,part,status,answer,time_us,extras".to_string();
+    if with_memory {
+        for phase in ["parse", "solve"] {
+            for column in ["peak_bytes", "total_bytes", "allocations"] {
+                header += &format!(",{}_{}", phase, column);
+            }
+        }
+    }
+    println!("{}", header);
+    for result in results {
+        let (status, answer, extras) = match &result.answer {
+            Ok(answer) => {
+                let extras = answer
+                    .extras
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, value))
+                    .collect::<Vec<_>>()
+                    .join(";");
+                ("ok", answer.value.to_string(), extras)
+            }
+            Err(err) => ("error", format!("{:#}", err), String::new()),
+        };
+        let mut row = format!(
+            "{},{},{},{},{},{}",
+            result.day,
+            result.part,
+            status,
+            csv_field(&answer),
+            result.elapsed.as_micros(),
+            csv_field(&extras)
+        );
+        if with_memory {
+            let memory = result.memory.unwrap_or(MemReport {
+                parse: MemStats::default(),
+                solve: MemStats::default(),
+            });
+            for stats in [memory.parse, memory.solve] {
+                row += &format!(
+                    ",{},{},{}",
+                    stats.peak_bytes, stats.total_bytes, stats.allocations
+                );
+            }
+        }
+        println!("{}", row);
+    }
+}
+
+fn print_table(results: &[RunResult]) {
+    let rows = results
+        .iter()
+        .map(|res| {
+            let (status, answer) = match &res.answer {
+                Ok(answer) => ("ok", answer.to_string()),
+                Err(err) => ("error", format!("{:#}", err)),
+            };
+            vec![
+                res.day.to_string(),
+                res.part.to_string(),
+                status.to_string(),
+                format!("{:.3?}", res.elapsed),
+                answer,
+            ]
+        })
+        .collect::<Vec<_>>();
+    print_rows(&["day", "part", "status", "time", "answer"], &rows);
+}
+
+fn print_memory_table(results: &[RunResult]) {
+    let rows = results
+        .iter()
+        .filter_map(|res| res.memory.map(|memory| (res, memory)))
+        .flat_map(|(res, memory)| {
+            [("parse", memory.parse), ("solve", memory.solve)].map(|(phase, stats)| {
+                vec![
+                    res.day.to_string(),
+                    res.part.to_string(),
+                    phase.to_string(),
+                    mem::format_bytes(stats.peak_bytes),
+                    mem::format_bytes(stats.total_bytes),
+                    stats.allocations.to_string(),
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+    print_rows(
+        &["day", "part", "phase", "peak", "total", "allocations"],
+        &rows,
+    );
+}
+
+fn print_rows(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths = header.iter().map(|h| h.len()).collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let print_row = |row: &[String]| {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+    print_row(&header.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    rows.iter().for_each(|row| print_row(row));
+}
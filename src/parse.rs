@@ -0,0 +1,138 @@
+use anyhow::Context as _;
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Error in an input file, pointing at the line and column of the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole line containing the error.
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error located at `token`, which must be a slice of `source`.
+    pub fn at(source: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = source.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= source.len())
+            .unwrap_or(0);
+        ParseError::at_offset(source, offset, message)
+    }
+
+    /// Creates an error located at the byte `offset` of `source`.
+    pub fn at_offset(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |pos| offset + pos);
+        ParseError {
+            file: None,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            snippet: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_path_buf());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "line ")?,
+        }
+        writeln!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(f, "    {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `source`, reporting its position on failure.
+pub fn parse_token<T>(source: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|err| ParseError::at(source, token, format!("invalid value '{}': {}", token, err)))
+}
+
+/// Reads the file and parses its content, attaching the file name to parse errors.
+pub fn parse_file<T>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> anyhow::Result<T> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(parse(&content).map_err(|err| err.with_file(path))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_of_token() {
+        let source = "1,2,3\n4,x,6\n";
+        let token = &source[8..9];
+        let err = ParseError::at(source, token, "bad");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.snippet, "4,x,6");
+        assert_eq!(err.to_string(), "line 2:3: bad\n    4,x,6\n      ^");
+    }
+
+    #[test]
+    fn test_position_at_end() {
+        let err = ParseError::at_offset("ab\ncd", 5, "unexpected end");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.snippet, "cd");
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let source = "10 -3 abc";
+        assert_eq!(parse_token::<i32>(source, &source[3..5]), Ok(-3));
+        let err = parse_token::<usize>(source, &source[6..]).unwrap_err();
+        assert_eq!(err.column, 7);
+        assert!(err.message.starts_with("invalid value 'abc'"));
+    }
+
+    #[test]
+    fn test_parse_file_names_file() {
+        let path = std::env::temp_dir().join(format!("aoc-parse-{}.txt", std::process::id()));
+        fs::write(&path, "12\n3a\n").unwrap();
+        let err = parse_file(&path, |content| {
+            content
+                .lines()
+                .map(|line| parse_token::<usize>(content, line))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err.file.as_deref(), Some(path.as_path()));
+        assert_eq!((err.line, err.column), (2, 1));
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::{Result, anyhow};
+
+use crate::solution::Day;
+
+/// The solutions of one event, each year lives in its own `y<year>` module.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(year: u16, days: &'static [Day]) -> Self {
+        Year { year, days }
+    }
+}
+
+pub const YEARS: &[Year] = &[Year::new(2025, crate::y2025::DAYS)];
+
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// Days registered for the year.
+pub fn days(year: u16) -> Result<&'static [Day]> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .map(|y| y.days)
+        .ok_or_else(|| anyhow!("no solutions registered for {}", year))
+}
+
+/// Parses a day number from `day_01`, `day01`, `01` or `1`.
+pub fn day_number(name: &str) -> Option<usize> {
+    name.trim_start_matches("day")
+        .trim_start_matches('_')
+        .parse::<usize>()
+        .ok()
+}
+
+pub fn day_name(number: usize) -> String {
+    format!("day_{:02}", number)
+}
+
+/// Finds a registered day by name, see [`day_number`] for the accepted formats.
+pub fn find(year: u16, name: &str) -> Option<&'static Day> {
+    let name = day_name(day_number(name)?);
+    days(year).ok()?.iter().find(|day| day.name == name)
+}
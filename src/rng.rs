@@ -0,0 +1,67 @@
+use std::ops::RangeInclusive;
+
+/// Small seeded pseudo-random generator (SplitMix64) used by the input generators. The
+/// sequence only depends on the seed, so a generated input can always be reproduced.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed value in the inclusive range.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(span) => low + (self.next_u64() % span as u64) as usize,
+            None => self.next_u64() as usize,
+        }
+    }
+
+    /// Returns true with the given probability in percent.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+        }
+        assert_eq!(rng.range(9..=9), 9);
+    }
+}
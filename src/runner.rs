@@ -0,0 +1,113 @@
+use anyhow::{Result, anyhow};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use tracing::{debug, debug_span};
+
+use crate::input::InputSource;
+use crate::mem::{self, MemReport};
+use crate::solution::{Answer, Context, Day, Part};
+
+pub struct RunResult {
+    pub day: &'static str,
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+    /// Heap usage of the run, only recorded with `--mem`.
+    pub memory: Option<MemReport>,
+}
+
+/// Runs a single part of a day, turning a panic inside the solution into an error.
+pub fn run(
+    day: &'static Day,
+    part: Part,
+    source: &InputSource,
+    params: &[(String, String)],
+) -> RunResult {
+    let start = Instant::now();
+    let mut memory = None;
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _span =
+            debug_span!("run", year = day.year, day = day.name, part = part.number()).entered();
+        let ctx = Context::new(day.params, source.name(day, part)?.as_deref(), params)?;
+        let path = source.resolve(day, part)?;
+        let (input, parse) = mem::measure(|| day.parse(&path));
+        let input = input?;
+        debug!(elapsed = ?start.elapsed(), "parsed {}", path.display());
+        let (answer, solve) = mem::measure(|| day.solve(input.as_ref(), part, &ctx));
+        if mem::is_enabled() {
+            memory = Some(MemReport { parse, solve });
+        }
+        let answer = answer?;
+        debug!(elapsed = ?start.elapsed(), "solved");
+        Ok(answer)
+    }))
+    .unwrap_or_else(|payload| Err(anyhow!("panicked: {}", panic_message(&payload))));
+    RunResult {
+        day: day.name,
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        memory,
+    }
+}
+
+pub(crate) fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub struct Task {
+    pub day: &'static Day,
+    pub part: Part,
+    pub source: InputSource,
+}
+
+/// Number of worker threads used when `--jobs` is not given.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Runs the tasks on `jobs` worker threads. The results are returned in the order of
+/// the tasks, regardless of which finishes first. Each parameter is only passed to
+/// the days declaring it.
+pub fn run_many(tasks: &[Task], params: &[(String, String)], jobs: usize) -> Vec<RunResult> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..tasks.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(task) = tasks.get(idx) else {
+                        break;
+                    };
+                    let params = params
+                        .iter()
+                        .filter(|(name, _)| task.day.params.iter().any(|p| p.name == name))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    let result = run(task.day, task.part, &task.source, &params);
+                    results.lock().unwrap()[idx] = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every task is run"))
+        .collect()
+}
@@ -0,0 +1,116 @@
+use anyhow::{Result, anyhow, bail};
+use std::{fs, path::PathBuf};
+
+use advent_of_code_2025::input::data_dir;
+use advent_of_code_2025::registry::{self, day_name};
+
+const TEMPLATE: &str = include_str!("template.rs");
+const LIB_PATH: &str = "src/lib.rs";
+const REGISTRY_PATH: &str = "src/registry.rs";
+
+/// Creates `src/y<year>/day_XX.rs` from the template together with its data directory
+/// and registers the new module, creating the year module when it is the first day of
+/// the year. Must be run from the repository root.
+pub fn new_day(year: u16, name: &str) -> Result<String> {
+    let number = registry::day_number(name).ok_or_else(|| anyhow!("invalid day: {}", name))?;
+    let name = day_name(number);
+    let module_dir = PathBuf::from(format!("src/y{}", year));
+    let source_path = module_dir.join(format!("{}.rs", name));
+    let data_dir = data_dir(year, &name);
+    if registry::find(year, &name).is_some() || source_path.exists() || data_dir.exists() {
+        bail!("{} of {} already exists", name, year);
+    }
+
+    let module_path = module_dir.join("mod.rs");
+    let declaration = format!("pub mod {};", name);
+    let entry = format!(
+        "    Day::new::<{}::Day{:02}>({}, \"{}\"),",
+        name, number, year, name
+    );
+    let mut updates = Vec::new();
+    if module_path.exists() {
+        let module = fs::read_to_string(&module_path)?;
+        let module = insert_sorted(&module, "pub mod day_", &declaration)?;
+        let module = insert_sorted(&module, "    Day::new::<day_", &entry)?;
+        updates.push((module_path, module));
+    } else {
+        let module = format!(
+            "//! Solutions of Advent of Code {}.\n\nuse crate::solution::Day;\n\n{}\n\npub const DAYS: &[Day] = &[\n{}\n];\n",
+            year, declaration, entry
+        );
+        let lib = fs::read_to_string(LIB_PATH)?;
+        let lib = insert_sorted(&lib, "pub mod y", &format!("pub mod y{};", year))?;
+        let registry = fs::read_to_string(REGISTRY_PATH)?;
+        let registry = insert_year(&registry, year)?;
+        updates.push((module_path, module));
+        updates.push((LIB_PATH.into(), lib));
+        updates.push((REGISTRY_PATH.into(), registry));
+    }
+
+    fs::create_dir_all(&module_dir)?;
+    fs::write(
+        &source_path,
+        TEMPLATE.replace("DayXX", &format!("Day{:02}", number)),
+    )?;
+    fs::create_dir_all(&data_dir)?;
+    fs::write(data_dir.join("input.txt"), "")?;
+    fs::write(data_dir.join("test.txt"), "")?;
+    for (path, content) in updates {
+        fs::write(path, content)?;
+    }
+    Ok(name)
+}
+
+/// Inserts `line` among the lines starting with `prefix`, before the first one sorting
+/// after it, or after the last one.
+fn insert_sorted(content: &str, prefix: &str, line: &str) -> Result<String> {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let last = lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix))
+        .ok_or_else(|| anyhow!("no line starting with '{}' found", prefix))?;
+    let pos = lines
+        .iter()
+        .position(|l| l.starts_with(prefix) && *l > line)
+        .unwrap_or(last + 1);
+    lines.insert(pos, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the year after the last `Year::new(..)` entry of the registry.
+fn insert_year(registry: &str, year: u16) -> Result<String> {
+    let start = registry
+        .rfind("Year::new(")
+        .ok_or_else(|| anyhow!("no Year::new entry found in {}", REGISTRY_PATH))?;
+    let end = start
+        + registry[start..]
+            .find(')')
+            .ok_or_else(|| anyhow!("unterminated Year::new entry in {}", REGISTRY_PATH))?
+        + 1;
+    Ok(format!(
+        "{}, Year::new({}, crate::y{}::DAYS){}",
+        &registry[..end],
+        year,
+        year,
+        &registry[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_sorted() {
+        let module = "pub mod day_01;\npub mod day_12;\n\nconst X: u8 = 0;\n";
+        assert_eq!(
+            insert_sorted(module, "pub mod day_", "pub mod day_05;").unwrap(),
+            "pub mod day_01;\npub mod day_05;\npub mod day_12;\n\nconst X: u8 = 0;\n"
+        );
+        assert_eq!(
+            insert_sorted(module, "pub mod day_", "pub mod day_13;").unwrap(),
+            "pub mod day_01;\npub mod day_12;\npub mod day_13;\n\nconst X: u8 = 0;\n"
+        );
+        assert!(insert_sorted(module, "pub mod y", "pub mod y2026;").is_err());
+    }
+}
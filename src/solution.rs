@@ -0,0 +1,327 @@
+use anyhow::{Result, anyhow, bail};
+use std::{any::Any, collections::HashMap, fmt::Display, path::Path, str::FromStr};
+
+use crate::rng::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Value of an answer. Puzzle answers are almost always numbers, but some are text.
+/// Numbers are stored as `i128` so every `i64` and `u64` answer fits without wrapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_value_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Value {
+            fn from(n: $t) -> Self {
+                Value::Number(n as i128)
+            }
+        })*
+    };
+}
+
+// lossless: i128 holds every value of these types
+impl_value_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
+
+/// Answer of a part: the value submitted to the puzzle plus optional named metrics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: Value,
+    pub extras: Vec<(String, Value)>,
+}
+
+impl Answer {
+    pub fn new(value: impl Into<Value>) -> Self {
+        Answer {
+            value: value.into(),
+            extras: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.extras.push((name.to_string(), value.into()));
+        self
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)?;
+        if !self.extras.is_empty() {
+            let extras = self
+                .extras
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect::<Vec<_>>();
+            write!(f, " ({})", extras.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Tunable puzzle parameter declared by a solution and set with `--param name=value`.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    /// Defaults overriding `default` for specific inputs, keyed by input name (e.g. `test`).
+    pub input_defaults: &'static [(&'static str, &'static str)],
+}
+
+impl Param {
+    pub fn default_for(&self, input: Option<&str>) -> &'static str {
+        self.input_defaults
+            .iter()
+            .find(|(name, _)| Some(*name) == input)
+            .map_or(self.default, |(_, value)| value)
+    }
+}
+
+/// Information about the current run that is not part of the puzzle input itself.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    params: HashMap<&'static str, String>,
+}
+
+impl Context {
+    /// Resolves the declared parameters for the given input and applies the overrides.
+    pub fn new(
+        params: &'static [Param],
+        input: Option<&str>,
+        overrides: &[(String, String)],
+    ) -> Result<Self> {
+        let mut values = params
+            .iter()
+            .map(|param| (param.name, param.default_for(input).to_string()))
+            .collect::<HashMap<_, _>>();
+        for (name, value) in overrides {
+            let param = params
+                .iter()
+                .find(|param| param.name == name)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "unknown parameter '{}', available: {}",
+                        name,
+                        params.iter().map(|p| p.name).collect::<Vec<_>>().join(", ")
+                    )
+                })?;
+            values.insert(param.name, value.clone());
+        }
+        Ok(Context { params: values })
+    }
+
+    pub fn param<T: FromStr>(&self, name: &str) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        let value = self
+            .params
+            .get(name)
+            .ok_or_else(|| anyhow!("parameter '{}' is not declared", name))?;
+        value
+            .parse()
+            .map_err(|e| anyhow!("invalid value '{}' of parameter '{}': {}", value, name, e))
+    }
+}
+
+/// A single day of the puzzle. Parsing is separated from solving so both parts
+/// can share the loaded input.
+pub trait Solution {
+    type Input: 'static;
+
+    const HAS_PART_TWO: bool = true;
+
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(data_path: &Path) -> Result<Self::Input>;
+
+    fn part_one(input: &Self::Input, ctx: &Context) -> Result<Answer>;
+
+    fn part_two(_input: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        bail!("second part is not implemented")
+    }
+
+    /// Name of the test file (without extension) used for the given part.
+    fn test_input(_part: Part) -> &'static str {
+        "test"
+    }
+
+    const HAS_GENERATOR: bool = false;
+
+    /// Size knobs of [`Solution::generate`], set with `--knob name=value`.
+    const KNOBS: &'static [Param] = &[];
+
+    /// Generates the content of a random valid input file.
+    fn generate(_rng: &mut Rng, _knobs: &Context) -> Result<String> {
+        bail!("no input generator")
+    }
+
+    const HAS_REFERENCE: bool = false;
+
+    /// Naive but obviously correct solution of a part, compared with the real one by
+    /// `crosscheck` on generated inputs.
+    fn reference(_input: &Self::Input, _part: Part, _ctx: &Context) -> Result<Answer> {
+        bail!("no reference implementation")
+    }
+}
+
+/// Type-erased registry entry of a [`Solution`].
+pub struct Day {
+    pub year: u16,
+    pub name: &'static str,
+    pub has_part_two: bool,
+    pub params: &'static [Param],
+    pub has_generator: bool,
+    pub knobs: &'static [Param],
+    parse: fn(&Path) -> Result<Box<dyn Any>>,
+    solve: fn(&dyn Any, Part, &Context) -> Result<Answer>,
+    test_input: fn(Part) -> &'static str,
+    generate: fn(&mut Rng, &Context) -> Result<String>,
+    pub has_reference: bool,
+    reference: fn(&dyn Any, Part, &Context) -> Result<Answer>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, name: &'static str) -> Self {
+        Day {
+            year,
+            name,
+            has_part_two: S::HAS_PART_TWO,
+            params: S::PARAMS,
+            parse: parse_erased::<S>,
+            solve: solve_erased::<S>,
+            test_input: S::test_input,
+            has_generator: S::HAS_GENERATOR,
+            knobs: S::KNOBS,
+            generate: S::generate,
+            has_reference: S::HAS_REFERENCE,
+            reference: reference_erased::<S>,
+        }
+    }
+
+    pub fn parts(&self) -> &'static [Part] {
+        match self.has_part_two {
+            true => &[Part::One, Part::Two],
+            false => &[Part::One],
+        }
+    }
+
+    pub fn parse(&self, data_path: &Path) -> Result<Box<dyn Any>> {
+        (self.parse)(data_path)
+    }
+
+    pub fn solve(&self, input: &dyn Any, part: Part, ctx: &Context) -> Result<Answer> {
+        if part == Part::Two && !self.has_part_two {
+            bail!("{} has no second part", self.name);
+        }
+        (self.solve)(input, part, ctx)
+    }
+
+    pub fn run(&self, data_path: &Path, part: Part, ctx: &Context) -> Result<Answer> {
+        let input = self.parse(data_path)?;
+        self.solve(input.as_ref(), part, ctx)
+    }
+
+    pub fn test_input(&self, part: Part) -> &'static str {
+        (self.test_input)(part)
+    }
+
+    /// Generates a random input from the seed. `input` selects the knob defaults like it
+    /// selects parameter defaults, `knobs` override them.
+    pub fn generate(
+        &self,
+        seed: u64,
+        input: Option<&str>,
+        knobs: &[(String, String)],
+    ) -> Result<String> {
+        if !self.has_generator {
+            bail!("{} has no input generator", self.name);
+        }
+        let knobs = Context::new(self.knobs, input, knobs)?;
+        (self.generate)(&mut Rng::new(seed), &knobs)
+    }
+
+    pub fn solve_reference(&self, input: &dyn Any, part: Part, ctx: &Context) -> Result<Answer> {
+        if !self.has_reference {
+            bail!("{} has no reference implementation", self.name);
+        }
+        (self.reference)(input, part, ctx)
+    }
+}
+
+fn parse_erased<S: Solution>(data_path: &Path) -> Result<Box<dyn Any>> {
+    Ok(Box::new(S::parse(data_path)?))
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("input does not belong to this solution"))
+}
+
+fn solve_erased<S: Solution>(input: &dyn Any, part: Part, ctx: &Context) -> Result<Answer> {
+    let input = downcast::<S>(input)?;
+    match part {
+        Part::One => S::part_one(input, ctx),
+        Part::Two => S::part_two(input, ctx),
+    }
+}
+
+fn reference_erased<S: Solution>(input: &dyn Any, part: Part, ctx: &Context) -> Result<Answer> {
+    S::reference(downcast::<S>(input)?, part, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_numbers_do_not_wrap() {
+        assert_eq!(Value::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Value::from(usize::MAX), Value::Number(usize::MAX as i128));
+        assert_eq!(Value::from(i64::MIN).to_string(), i64::MIN.to_string());
+    }
+}
@@ -0,0 +1,510 @@
+use anyhow::{Context as _, Result, anyhow, bail};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tracing::warn;
+
+use crate::client::Client;
+use crate::solution::Part;
+
+/// Cooldown assumed when the website asks to wait without saying for how long.
+const DEFAULT_COOLDOWN_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked because of a previous wrong answer.
+    Cooldown,
+    /// The part was already solved, the answer was not checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::Cooldown => "cooldown",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::Cooldown,
+            Verdict::AlreadySolved,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.as_str() == s)
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "wrong, too high",
+            Verdict::TooLow => "wrong, too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Cooldown => "not checked, answered too recently",
+            Verdict::AlreadySolved => "not checked, part already solved",
+            Verdict::Unknown => "unrecognized response",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Reads the verdict and the requested cooldown in seconds from the response page.
+pub fn parse_response(body: &str) -> (Verdict, Option<u64>) {
+    if body.contains("That's the right answer") {
+        (Verdict::Correct, None)
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_duration(wait));
+        (
+            Verdict::Cooldown,
+            Some(wait.unwrap_or(DEFAULT_COOLDOWN_SECS)),
+        )
+    } else if body.contains("That's not the right answer") {
+        let verdict = if body.contains("too high") {
+            Verdict::TooHigh
+        } else if body.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = body
+            .to_lowercase()
+            .split_once("please wait ")
+            .and_then(|(_, rest)| rest.split_once(" before trying again"))
+            .and_then(|(wait, _)| parse_duration(wait));
+        (verdict, Some(wait.unwrap_or(DEFAULT_COOLDOWN_SECS)))
+    } else if body.contains("You don't seem to be solving the right level") {
+        (Verdict::AlreadySolved, None)
+    } else {
+        (Verdict::Unknown, None)
+    }
+}
+
+/// Parses durations such as `1m 30s`, `45s` or `one minute`.
+fn parse_duration(s: &str) -> Option<u64> {
+    let words = s.split_whitespace().collect::<Vec<_>>();
+    if let [amount, unit] = words.as_slice()
+        && unit.starts_with("minute")
+    {
+        let amount = match *amount {
+            "one" => 1,
+            "two" => 2,
+            "five" => 5,
+            "ten" => 10,
+            amount => amount.parse().ok()?,
+        };
+        return Some(amount * 60);
+    }
+    words.iter().try_fold(0, |total, word| {
+        let (amount, unit) = word.split_at(word.len().checked_sub(1)?);
+        let amount = amount.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(total + amount * 3600),
+            "m" => Some(total + amount * 60),
+            "s" => Some(total + amount),
+            _ => None,
+        }
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp of the submission.
+    pub time: u64,
+}
+
+/// Submitted answers of a day stored in `data/<year>/<day>/submissions.toml`, together with
+/// the time until which the website refuses new answers.
+#[derive(Debug, Default)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+    pub cooldown_until: Option<u64>,
+}
+
+impl History {
+    /// Loads the history, a missing file is treated as an empty history.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let table = fs::read_to_string(path)?
+            .parse::<toml::Table>()
+            .with_context(|| format!("parsing {}", path.display()))?;
+        let cooldown_until = table
+            .get("cooldown_until")
+            .and_then(toml::Value::as_integer)
+            .map(|time| time as u64);
+        let attempts = match table.get("attempt") {
+            None => Vec::new(),
+            Some(attempts) => attempts
+                .as_array()
+                .ok_or_else(|| anyhow!("attempt is not an array"))?
+                .iter()
+                .map(parse_attempt)
+                .collect::<Result<_>>()
+                .with_context(|| format!("parsing {}", path.display()))?,
+        };
+        Ok(History {
+            attempts,
+            cooldown_until,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut table = toml::Table::new();
+        if let Some(until) = self.cooldown_until {
+            table.insert("cooldown_until".into(), toml::Value::Integer(until as i64));
+        }
+        let attempts = self
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let mut entry = toml::Table::new();
+                entry.insert("part".into(), (attempt.part.number() as i64).into());
+                entry.insert("answer".into(), attempt.answer.clone().into());
+                entry.insert("verdict".into(), attempt.verdict.as_str().into());
+                entry.insert("time".into(), (attempt.time as i64).into());
+                toml::Value::Table(entry)
+            })
+            .collect::<Vec<_>>();
+        table.insert("attempt".into(), toml::Value::Array(attempts));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string(&table)?)?;
+        Ok(())
+    }
+
+    fn attempts_of(&self, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.part == part)
+    }
+
+    /// Smallest answer known to be too high and largest answer known to be too low.
+    pub fn bounds(&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let guesses = |verdict| {
+            self.attempts_of(part)
+                .filter(move |attempt| attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        (
+            guesses(Verdict::TooHigh).min(),
+            guesses(Verdict::TooLow).max(),
+        )
+    }
+}
+
+fn parse_attempt(value: &toml::Value) -> Result<Attempt> {
+    let entry = value
+        .as_table()
+        .ok_or_else(|| anyhow!("attempt is not a table"))?;
+    let field = |name: &str| {
+        entry
+            .get(name)
+            .ok_or_else(|| anyhow!("attempt without {}", name))
+    };
+    let part = match field("part")?.as_integer() {
+        Some(1) => Part::One,
+        Some(2) => Part::Two,
+        _ => bail!("invalid part: {}", field("part")?),
+    };
+    let answer = match field("answer")? {
+        toml::Value::String(answer) => answer.clone(),
+        value => value.to_string(),
+    };
+    let verdict = field("verdict")?
+        .as_str()
+        .and_then(Verdict::from_str)
+        .ok_or_else(|| anyhow!("invalid verdict: {}", entry["verdict"]))?;
+    let time = field("time")?
+        .as_integer()
+        .ok_or_else(|| anyhow!("invalid time: {}", entry["time"]))? as u64;
+    Ok(Attempt {
+        part,
+        answer,
+        verdict,
+        time,
+    })
+}
+
+pub fn history_path(day_dir: &Path) -> PathBuf {
+    day_dir.join("submissions.toml")
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Checks the answer against the history before submitting it: answers already marked
+/// wrong are refused, as are submissions during a cooldown. Guesses outside the known
+/// too-high/too-low bounds are submitted with a warning.
+pub fn check(history: &History, part: Part, answer: &str, now: u64) -> Result<()> {
+    if let Some(attempt) = history
+        .attempts_of(part)
+        .find(|attempt| attempt.verdict == Verdict::Correct)
+    {
+        bail!("part {} is already solved with {}", part, attempt.answer);
+    }
+    if let Some(attempt) = history
+        .attempts_of(part)
+        .find(|attempt| attempt.answer == answer && attempt.verdict.is_wrong())
+    {
+        bail!(
+            "{} was already submitted and is {}",
+            answer,
+            attempt.verdict
+        );
+    }
+    if let Some(until) = history.cooldown_until.filter(|until| *until > now) {
+        bail!(
+            "answered too recently, wait {}s before submitting",
+            until - now
+        );
+    }
+    if let Some(warning) = bounds_warning(history, part, answer) {
+        warn!("{}", warning);
+    }
+    Ok(())
+}
+
+/// Why the answer cannot be right given the too-high/too-low answers of the history.
+pub fn bounds_warning(history: &History, part: Part, answer: &str) -> Option<String> {
+    let guess = answer.parse::<i128>().ok()?;
+    let reason = match history.bounds(part) {
+        (Some(high), _) if guess >= high => format!("{} answered too high", high),
+        (_, Some(low)) if guess <= low => format!("{} answered too low", low),
+        _ => return None,
+    };
+    Some(format!(
+        "{} is outside the known bounds ({})",
+        answer, reason
+    ))
+}
+
+pub struct Submission<'a> {
+    pub year: u16,
+    pub day: usize,
+    pub part: Part,
+    pub answer: &'a str,
+}
+
+/// Submits the answer of a part, recording the attempt and cooldown in the history file.
+pub fn submit(
+    client: &Client,
+    submission: &Submission,
+    history_path: &Path,
+    now: u64,
+) -> Result<Verdict> {
+    let Submission {
+        year,
+        day,
+        part,
+        answer,
+    } = *submission;
+    let mut history = History::load(history_path)?;
+    check(&history, part, answer, now)?;
+    let body = client.post_form(
+        &format!("{}/day/{}/answer", year, day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let (verdict, cooldown) = parse_response(&body);
+    history.attempts.push(Attempt {
+        part,
+        answer: answer.to_string(),
+        verdict,
+        time: now,
+    });
+    history.cooldown_until = cooldown.map(|wait| now + wait);
+    history.save(history_path)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    const CORRECT: &str =
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. \
+        Please wait 5 minutes before trying again.</p></article>";
+    const TOO_RECENT: &str =
+        "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>";
+
+    fn temp_history(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("aoc-submit-{}-{}", std::process::id(), name))
+            .join("submissions.toml")
+    }
+
+    fn submission(day: usize, part: Part, answer: &str) -> Submission<'_> {
+        Submission {
+            year: 2025,
+            day,
+            part,
+            answer,
+        }
+    }
+
+    fn client(server: &MockServer) -> Client {
+        Client::new(&server.base_url, Some("secret".to_string()))
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response(CORRECT), (Verdict::Correct, None));
+        assert_eq!(parse_response(TOO_HIGH), (Verdict::TooHigh, Some(60)));
+        assert_eq!(parse_response(TOO_LOW), (Verdict::TooLow, Some(300)));
+        assert_eq!(parse_response(TOO_RECENT), (Verdict::Cooldown, Some(65)));
+        assert_eq!(parse_response("<html></html>"), (Verdict::Unknown, None));
+    }
+
+    #[test]
+    fn test_submit_records_history() {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let path = temp_history("record");
+
+        let verdict = submit(
+            &client(&server),
+            &submission(3, Part::One, "500"),
+            &path,
+            1000,
+        );
+        assert_eq!(verdict.unwrap(), Verdict::TooHigh);
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.cooldown_until, Some(1060));
+        assert_eq!(history.bounds(Part::One), (Some(500), None));
+
+        let verdict = submit(
+            &client(&server),
+            &submission(3, Part::One, "400"),
+            &path,
+            2000,
+        );
+        assert_eq!(verdict.unwrap(), Verdict::Correct);
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.attempts.len(), 2);
+        assert_eq!(history.cooldown_until, None);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/2025/day/3/answer");
+        assert_eq!(requests[1].body, "level=1&answer=400");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_guards_refuse_without_request() {
+        let history = History {
+            attempts: vec![
+                Attempt {
+                    part: Part::Two,
+                    answer: "100".to_string(),
+                    verdict: Verdict::TooLow,
+                    time: 0,
+                },
+                Attempt {
+                    part: Part::Two,
+                    answer: "200".to_string(),
+                    verdict: Verdict::TooHigh,
+                    time: 0,
+                },
+            ],
+            cooldown_until: Some(50),
+        };
+        let path = temp_history("guards");
+        history.save(&path).unwrap();
+        // The server answers nothing, any request made would fail the submission.
+        let server = MockServer::start(vec![]);
+        let client = client(&server);
+
+        let refused = |answer: &str, now: u64| {
+            submit(&client, &submission(1, Part::Two, answer), &path, now)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(refused("100", 100).contains("already submitted"));
+        assert!(refused("150", 10).contains("wait 40s"));
+        assert!(check(&history, Part::Two, "150", 100).is_ok());
+        assert!(server.requests().is_empty());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_guesses_outside_bounds_are_only_warned_about() {
+        let history = History {
+            attempts: vec![
+                Attempt {
+                    part: Part::Two,
+                    answer: "100".to_string(),
+                    verdict: Verdict::TooLow,
+                    time: 0,
+                },
+                Attempt {
+                    part: Part::Two,
+                    answer: "200".to_string(),
+                    verdict: Verdict::TooHigh,
+                    time: 0,
+                },
+            ],
+            cooldown_until: None,
+        };
+        let warning = |answer| bounds_warning(&history, Part::Two, answer);
+        assert_eq!(
+            warning("250").as_deref(),
+            Some("250 is outside the known bounds (200 answered too high)")
+        );
+        assert!(warning("50").unwrap().contains("100 answered too low"));
+        assert_eq!(warning("150"), None);
+        assert_eq!(bounds_warning(&history, Part::One, "250"), None);
+        assert!(check(&history, Part::Two, "250", 100).is_ok());
+
+        let server = MockServer::start(vec![(200, TOO_HIGH)]);
+        let path = temp_history("bounds");
+        history.save(&path).unwrap();
+        let verdict = submit(
+            &client(&server),
+            &submission(1, Part::Two, "250"),
+            &path,
+            100,
+        );
+        assert_eq!(verdict.unwrap(), Verdict::TooHigh);
+        assert_eq!(server.requests()[0].body, "level=2&answer=250");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
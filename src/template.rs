@@ -1,10 +1,34 @@
 use anyhow::Result;
 use std::path::Path;
+use tracing::debug;
 
-pub fn task_01(data_path: &Path) -> Result<String> {
-    Ok(format!("Answer: {}", 42))
+use crate::solution::{Answer, Context, Solution};
+use crate::utils::read_lines;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<String>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let lines = read_lines(data_path)?;
+        debug!(lines = lines.len(), "parsed lines");
+        Ok(lines)
+    }
+
+    fn part_one(lines: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(lines)
+    }
+
+    fn part_two(lines: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(lines)
+    }
+}
+
+pub fn task_01(lines: &[String]) -> Result<Answer> {
+    Ok(Answer::new(lines.len()))
 }
 
-pub fn task_02(data_path: &Path) -> Result<String> {
-    Ok(format!("Answer: {}", 42))
+pub fn task_02(lines: &[String]) -> Result<Answer> {
+    Ok(Answer::new(lines.len()))
 }
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+/// Union-find over the elements `0..len`, with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Every element starts in a component of its own.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Representative of the element's component.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the components of both elements, returns false when they already were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (large, small) = if self.size[a] < self.size[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the element's component.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Elements of every component in ascending order, the components ordered by their
+    /// smallest element.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut index = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let idx = *index.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[idx].push(element);
+        }
+        components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_and_find() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.component_count(), 6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 1));
+        assert!(!set.union(0, 2));
+        assert!(set.union(4, 5));
+        assert!(set.connected(0, 2));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.component_size(2), 3);
+        assert_eq!(set.component_size(3), 1);
+        assert_eq!(set.component_count(), 3);
+        assert_eq!(
+            set.components().collect::<Vec<_>>(),
+            vec![vec![0, 1, 2], vec![3], vec![4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_long_chain_is_compressed() {
+        // union by size never builds a chain, so link one by hand: 0 -> 1 -> ... -> 9999
+        let mut set = DisjointSet::new(10_000);
+        for i in 0..9_999 {
+            set.parent[i] = i + 1;
+        }
+        set.size[9_999] = 10_000;
+        set.components = 1;
+        assert_eq!(set.find(0), 9_999);
+        assert!((0..10_000).all(|i| set.parent[i] == 9_999));
+        assert_eq!(set.component_size(0), 10_000);
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::parse::ParseError;
+use crate::utils::Point2;
+
+/// Rectangular grid stored row by row in a single vector, indexed by [`Point2`] positions
+/// with `x` the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per line, mapping each character with `cell`. Characters it
+    /// rejects and rows of a different width than the first are reported as errors.
+    pub fn parse(source: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Grid::parse_in(source, source, cell)
+    }
+
+    /// Like [`Grid::parse`] for `rows`, a slice of `source`, reporting errors at their
+    /// position in `source`.
+    pub fn parse_in(
+        source: &str,
+        rows: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in rows.lines() {
+            let row_start = cells.len();
+            for (pos, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(
+                        source,
+                        &line[pos..pos + c.len_utf8()],
+                        format!("unexpected character '{}' in grid", c),
+                    )
+                })?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        source,
+                        line,
+                        format!("expected {} columns, found {}", width, row_width),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point2<usize>) -> bool {
+        pos.x() < self.width && pos.y() < self.height
+    }
+
+    pub fn get(&self, pos: Point2<usize>) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y() * self.width + pos.x()])
+    }
+
+    pub fn get_mut(&mut self, pos: Point2<usize>) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y() * self.width + pos.x()])
+    }
+
+    /// Position one step in `dir`, one of the [`Point2`] directions, if it is still inside
+    /// the grid.
+    pub fn step(&self, pos: Point2<usize>, dir: Point2<isize>) -> Option<Point2<usize>> {
+        let x = pos.x().checked_add_signed(dir.x())?;
+        let y = pos.y().checked_add_signed(dir.y())?;
+        Some(Point2::new(x, y)).filter(|&next| self.contains(next))
+    }
+
+    /// Orthogonal neighbors inside the grid.
+    pub fn neighbors4(&self, pos: Point2<usize>) -> impl Iterator<Item = Point2<usize>> {
+        Point2::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Orthogonal and diagonal neighbors inside the grid.
+    pub fn neighbors8(&self, pos: Point2<usize>) -> impl Iterator<Item = Point2<usize>> {
+        Point2::ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point2<usize>> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// Cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point2<usize>>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2<usize>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2<usize>) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("ab.\n.c#\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(1, 1)], 'c');
+        assert_eq!(grid[Point2::new(2, 0)], '.');
+        assert_eq!(grid.get(Point2::new(0, 2)), None);
+        assert_eq!(grid.row(1), &['.', 'c', '#']);
+        assert_eq!(grid.column(2).collect::<String>(), ".#");
+        assert_eq!(grid.find(&'#'), Some(Point2::new(2, 1)));
+        assert_eq!(grid.to_string(), "ab.\n.c#\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("..\n.x\n", |c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert!(err.message.contains("'x'"));
+        let err = Grid::parse("..\n...\n", Some).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "expected 2 columns, found 3");
+        let source = "0:\n#.\n.x\n";
+        let err = Grid::parse_in(source, &source[3..], |c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        let mut corner = grid.neighbors8(Point2::ORIGIN).collect::<Vec<_>>();
+        corner.sort();
+        assert_eq!(
+            corner,
+            vec![Point2::new(0, 1), Point2::new(1, 0), Point2::new(1, 1)]
+        );
+        assert_eq!(grid.neighbors4(Point2::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8(Point2::new(1, 1)).count(), 5);
+        assert_eq!(
+            grid.step(Point2::new(2, 0), Point2::DOWN),
+            Some(Point2::new(2, 1))
+        );
+        assert_eq!(grid.step(Point2::new(2, 0), Point2::RIGHT), None);
+        assert_eq!(grid.step(Point2::new(2, 0), Point2::UP), None);
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt::{self, Display};
+
+use super::Range;
+
+/// Set of `usize` values stored as sorted, disjoint ranges. Overlapping and adjacent
+/// ranges are merged on insert, so the ranges are never touching either.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+    total: usize,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Adds the range, merging it with every range it overlaps or touches.
+    pub fn insert(&mut self, range: Range) {
+        // ranges before `first` end before the gap in front of `range`, ranges from
+        // `last` on start after the gap behind it
+        let first = self
+            .ranges
+            .partition_point(|r| r.end.saturating_add(1) < range.start);
+        let last = self
+            .ranges
+            .partition_point(|r| r.start <= range.end.saturating_add(1));
+        let mut merged = range;
+        for r in &self.ranges[first..last] {
+            merged = merged.merge(r);
+            self.total -= size(r);
+        }
+        self.total = self.total.saturating_add(size(&merged));
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Whether the value is in one of the ranges, in O(log n).
+    pub fn contains(&self, value: usize) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end < value);
+        self.ranges.get(idx).is_some_and(|r| r.contains(value))
+    }
+
+    /// Number of values in the set, saturating at `usize::MAX`.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Range> {
+        self.ranges.iter()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in other {
+            union.insert(*range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intersection = IntervalSet::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(ra), Some(rb)) = (a.peek(), b.peek()) {
+            if ra.overlaps(rb) {
+                intersection.insert(Range {
+                    start: ra.start.max(rb.start),
+                    end: ra.end.min(rb.end),
+                });
+            }
+            if ra.end < rb.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        intersection
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = IntervalSet::new();
+        for range in &self.ranges {
+            for remaining in &other.complement(*range) {
+                difference.insert(*remaining);
+            }
+        }
+        difference
+    }
+
+    /// The values within `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Range) -> IntervalSet {
+        let mut complement = IntervalSet::new();
+        let mut next = Some(bounds.start);
+        let first = self.ranges.partition_point(|r| r.end < bounds.start);
+        for r in self.ranges[first..]
+            .iter()
+            .take_while(|r| r.start <= bounds.end)
+        {
+            let Some(start) = next else {
+                break;
+            };
+            if r.start > start {
+                complement.insert(Range {
+                    start,
+                    end: r.start - 1,
+                });
+            }
+            next = r.end.checked_add(1);
+        }
+        if let Some(start) = next.filter(|&start| start <= bounds.end) {
+            complement.insert(Range {
+                start,
+                end: bounds.end,
+            });
+        }
+        complement
+    }
+}
+
+fn size(range: &Range) -> usize {
+    (range.end - range.start).saturating_add(1)
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Range;
+    type IntoIter = std::slice::Iter<'a, Range>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+impl FromIterator<Range> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl From<&[Range]> for IntervalSet {
+    fn from(ranges: &[Range]) -> Self {
+        ranges.iter().copied().collect()
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges = self.ranges.iter().map(Range::to_string).collect::<Vec<_>>();
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(usize, usize)]) -> IntervalSet {
+        ranges
+            .iter()
+            .map(|&(start, end)| Range { start, end })
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_adjacent() {
+        let mut s = set(&[(10, 20), (30, 40), (50, 60)]);
+        assert_eq!(s.total(), 33);
+        s.insert(Range { start: 21, end: 29 });
+        assert_eq!(s, set(&[(10, 40), (50, 60)]));
+        s.insert(Range { start: 5, end: 55 });
+        assert_eq!(s, set(&[(5, 60)]));
+        assert_eq!(s.total(), 56);
+        s.insert(Range { start: 0, end: 3 });
+        assert_eq!(s.to_string(), "{[0, 3], [5, 60]}");
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(0, 5), (10, 20), (30, 30)]);
+        for value in [0, 5, 10, 15, 20, 30] {
+            assert!(s.contains(value), "{}", value);
+        }
+        for value in [6, 9, 21, 29, 31, usize::MAX] {
+            assert!(!s.contains(value), "{}", value);
+        }
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (40, 50)]);
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 50)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (26, 30)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (40, 50)]));
+        let bounds = Range { start: 3, end: 45 };
+        assert_eq!(a.complement(bounds), set(&[(11, 19), (31, 45)]));
+        assert_eq!(
+            set(&[(0, usize::MAX)]).complement(bounds),
+            IntervalSet::new()
+        );
+    }
+}
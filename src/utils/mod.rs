@@ -3,6 +3,18 @@ use std::fmt::{self, Display};
 use std::mem;
 use std::{fs, path::Path};
 
+use crate::parse::{ParseError, parse_token};
+
+pub mod disjoint_set;
+pub mod grid;
+pub mod interval_set;
+pub mod point;
+
+pub use disjoint_set::DisjointSet;
+pub use grid::Grid;
+pub use interval_set::IntervalSet;
+pub use point::{Point, Point2, Point3};
+
 pub fn read_lines(path: &Path) -> Result<Vec<String>> {
     let content = fs::read_to_string(path)?;
     let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
@@ -17,28 +29,6 @@ pub fn digit_count(n: usize) -> usize {
     }
 }
 
-#[derive(Debug)]
-pub struct Point2D {
-    pub x: usize,
-    pub y: usize,
-}
-
-#[derive(Debug)]
-pub struct Point3D {
-    pub x: usize,
-    pub y: usize,
-    pub z: usize,
-}
-
-impl Point3D {
-    pub fn l2(&self, b: &Point3D) -> f64 {
-        (((self.x as isize - b.x as isize).pow(2)
-            + (self.y as isize - b.y as isize).pow(2)
-            + (self.z as isize - b.z as isize).pow(2)) as f64)
-            .sqrt()
-    }
-}
-
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub struct Range {
     pub start: usize,
@@ -72,30 +62,47 @@ impl Range {
     }
 }
 
-pub fn create_range(s: &str) -> Result<Range> {
-    let split = s.split('-').collect::<Vec<&str>>();
-    assert!(split.len() == 2);
-    let start = split[0].parse::<usize>()?;
-    let end = split[1].parse::<usize>()?;
-    assert!(start <= end);
+/// Parses a `start-end` range from `token`, a slice of `source`.
+pub fn create_range(source: &str, token: &str) -> Result<Range, ParseError> {
+    let (start, end) = token
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(source, token, "expected a range 'start-end'"))?;
+    let start = parse_token::<usize>(source, start)?;
+    let end = parse_token::<usize>(source, end)?;
+    if start > end {
+        return Err(ParseError::at(
+            source,
+            token,
+            format!("range start {} is after its end {}", start, end),
+        ));
+    }
     Ok(Range { start, end })
 }
 
-pub fn make_ranges(lines: String, split_pattern: char) -> Result<Vec<Range>> {
-    let ranges = lines
+/// Parses ranges separated by `split_pattern`, ignoring whitespace around them.
+pub fn make_ranges(source: &str, split_pattern: char) -> Result<Vec<Range>, ParseError> {
+    source
         .split(split_pattern)
-        .map(create_range)
-        .collect::<Result<Vec<Range>>>()?;
-    Ok(ranges)
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| create_range(source, token))
+        .collect()
 }
-// Currently the implementation does not handle overlapping ranges correctly.
+
+/// Binary search tree of disjoint ranges, kept AVL balanced. Inserted ranges that overlap
+/// stored ones are merged with them, so the nodes never overlap.
+/// The fields stay private so the stored heights always match the children.
 #[derive(Debug, PartialEq, Eq)]
 pub struct RangedBinaryTree {
-    pub value: Range,
-    pub left: Option<Box<RangedBinaryTree>>,
-    pub right: Option<Box<RangedBinaryTree>>,
+    value: Range,
+    left: Option<Box<RangedBinaryTree>>,
+    right: Option<Box<RangedBinaryTree>>,
+    /// Number of nodes on the longest path down from this node, 1 for a leaf.
+    height: usize,
 }
 
+type Link = Option<Box<RangedBinaryTree>>;
+
 impl Display for RangedBinaryTree {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.fmt_helper(f, "", true, "Root")
@@ -108,75 +115,25 @@ impl RangedBinaryTree {
             value,
             left: None,
             right: None,
+            height: 1,
         }
     }
 
     pub fn insert(&mut self, new_value: Range) {
-        if new_value.is_contained_in(&self.value) {
-            return;
-        }
-        if self.value.is_contained_in(&new_value) {
-            self.value = new_value;
-        } else if new_value.end <= self.value.start || new_value.start <= self.value.start {
-            match &mut self.left {
-                Some(node) => {
-                    node.insert(new_value);
-                }
-                None => {
-                    self.left = Some(Box::new(RangedBinaryTree::new(new_value)));
-                }
-            }
-        } else if new_value.start >= self.value.end || new_value.start > self.value.start {
-            match &mut self.right {
-                Some(node) => {
-                    node.insert(new_value);
-                }
-                None => {
-                    self.right = Some(Box::new(RangedBinaryTree::new(new_value)));
-                }
-            }
-        } else {
-            panic!(
-                "Overlapping ranges are not supported: {:?} vs {:?}",
-                self.value, new_value
-            );
-        }
-        self.normalize()
+        let root = mem::replace(self, RangedBinaryTree::new(self.value));
+        *self = *insert(Some(Box::new(root)), new_value);
     }
 
-    fn normalize(&mut self) {
-        if let Some(left) = &self.left
-            && left.value.end >= self.value.start
-            && left.value.start <= self.value.end
-        {
-            let mut left = mem::take(&mut self.left).unwrap();
-            if !left.right_is_none() {
-                left.insert(self.value);
-            }
-            assert!(
-                left.right_is_none(),
-                "Left node expects to have no right children: {}",
-                left
-            );
-            self.value.start = left.value.start;
-            self.left = left.left;
-        }
-        if let Some(right) = &self.right
-            && right.value.start <= self.value.end
-            && right.value.end >= self.value.start
-        {
-            let mut right = mem::take(&mut self.right).unwrap();
-            if !right.left_is_none() {
-                right.insert(self.value);
-            }
-            assert!(
-                right.left_is_none(),
-                "Right node expects to have no left children: {}",
-                right
-            );
-            self.value.end = right.value.end;
-            self.right = right.right;
-        }
+    pub fn value(&self) -> Range {
+        self.value
+    }
+
+    pub fn left(&self) -> Option<&RangedBinaryTree> {
+        self.left.as_deref()
+    }
+
+    pub fn right(&self) -> Option<&RangedBinaryTree> {
+        self.right.as_deref()
     }
 
     pub fn left_is_none(&self) -> bool {
@@ -204,25 +161,20 @@ impl RangedBinaryTree {
     }
 
     pub fn search(&self, value: usize) -> bool {
-        if self.value.contains(value) {
-            return true;
-        }
-
-        if let Some(left) = &self.left
-            && (value <= self.value.start || value <= self.value.end)
-            && left.search(value)
-        {
-            return true;
-        }
-
-        if let Some(right) = &self.right
-            && (value >= self.value.end || value >= self.value.start)
-            && right.search(value)
-        {
-            return true;
+        let mut node = self;
+        loop {
+            let next = if value < node.value.start {
+                &node.left
+            } else if value > node.value.end {
+                &node.right
+            } else {
+                return true;
+            };
+            match next {
+                Some(next) => node = next,
+                None => return false,
+            }
         }
-
-        false
     }
 
     pub fn from(new_values: &[Range]) -> Self {
@@ -267,6 +219,120 @@ impl RangedBinaryTree {
     }
 }
 
+fn height(link: &Link) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+/// Attaches the children and recomputes the height, without rebalancing.
+fn node(left: Link, mut mid: Box<RangedBinaryTree>, right: Link) -> Box<RangedBinaryTree> {
+    mid.height = height(&left).max(height(&right)) + 1;
+    mid.left = left;
+    mid.right = right;
+    mid
+}
+
+fn rotate_left(mut root: Box<RangedBinaryTree>) -> Box<RangedBinaryTree> {
+    let mut pivot = root.right.take().expect("rotation needs a right child");
+    let root = node(root.left.take(), root, pivot.left.take());
+    let right = pivot.right.take();
+    node(Some(root), pivot, right)
+}
+
+fn rotate_right(mut root: Box<RangedBinaryTree>) -> Box<RangedBinaryTree> {
+    let mut pivot = root.left.take().expect("rotation needs a left child");
+    let right = root.right.take();
+    let root = node(pivot.right.take(), root, right);
+    let left = pivot.left.take();
+    node(left, pivot, Some(root))
+}
+
+/// Joins two balanced trees with `mid` between them into one balanced tree, every range
+/// in `left` lying before `mid` and every range in `right` after it. Takes time in the
+/// order of the height difference.
+fn join(left: Link, mid: Box<RangedBinaryTree>, right: Link) -> Box<RangedBinaryTree> {
+    let (hl, hr) = (height(&left), height(&right));
+    if hl > hr + 1 {
+        let mut left = left.unwrap();
+        let inner = join(left.right.take(), mid, right);
+        let outer = left.left.take();
+        if inner.height <= height(&outer) + 1 {
+            node(outer, left, Some(inner))
+        } else if height(&inner.left) > height(&inner.right) {
+            rotate_left(node(outer, left, Some(rotate_right(inner))))
+        } else {
+            rotate_left(node(outer, left, Some(inner)))
+        }
+    } else if hr > hl + 1 {
+        let mut right = right.unwrap();
+        let inner = join(left, mid, right.left.take());
+        let outer = right.right.take();
+        if inner.height <= height(&outer) + 1 {
+            node(Some(inner), right, outer)
+        } else if height(&inner.right) > height(&inner.left) {
+            rotate_right(node(Some(rotate_left(inner)), right, outer))
+        } else {
+            rotate_right(node(Some(inner), right, outer))
+        }
+    } else {
+        node(left, mid, right)
+    }
+}
+
+/// Removes the ranges ending at or after `bound`, returning the rest and the smallest
+/// start of the removed ones.
+fn split_before(link: Link, bound: usize) -> (Link, Option<usize>) {
+    let Some(mut root) = link else {
+        return (None, None);
+    };
+    if root.value.end >= bound {
+        // the right subtree lies after the root, so it is removed as well
+        let (left, start) = split_before(root.left.take(), bound);
+        (left, Some(start.unwrap_or(root.value.start)))
+    } else {
+        let (right, start) = split_before(root.right.take(), bound);
+        let left = root.left.take();
+        (Some(join(left, root, right)), start)
+    }
+}
+
+/// Removes the ranges starting at or before `bound`, returning the rest and the largest
+/// end of the removed ones.
+fn split_after(link: Link, bound: usize) -> (Link, Option<usize>) {
+    let Some(mut root) = link else {
+        return (None, None);
+    };
+    if root.value.start <= bound {
+        let (right, end) = split_after(root.right.take(), bound);
+        (right, Some(end.unwrap_or(root.value.end)))
+    } else {
+        let (left, end) = split_after(root.left.take(), bound);
+        let right = root.right.take();
+        (Some(join(left, root, right)), end)
+    }
+}
+
+fn insert(link: Link, new_value: Range) -> Box<RangedBinaryTree> {
+    let Some(mut root) = link else {
+        return Box::new(RangedBinaryTree::new(new_value));
+    };
+    let (mut left, mut right) = (root.left.take(), root.right.take());
+    if new_value.end < root.value.start {
+        left = Some(insert(left, new_value));
+    } else if new_value.start > root.value.end {
+        right = Some(insert(right, new_value));
+    } else {
+        // Every other stored range overlapping the new one is a neighbour of this node
+        // in the subtrees, absorb them.
+        root.value = root.value.merge(&new_value);
+        let (kept, start) = split_before(left, root.value.start);
+        let (kept_right, end) = split_after(right, root.value.end);
+        root.value.start = start.map_or(root.value.start, |start| start.min(root.value.start));
+        root.value.end = end.map_or(root.value.end, |end| end.max(root.value.end));
+        (left, right) = (kept, kept_right);
+    }
+    join(left, root, right)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +349,12 @@ mod tests {
         assert!(!tree.search(100)); // Not in tree
         assert!(!tree.search(8)); // Gap between Left and Root
         assert_eq!(tree.total(), 28); // 11 (10-20) + 6 (0-5) + 11 (30-40)
+        assert_eq!(tree.value(), Range { start: 10, end: 20 });
+        assert_eq!(
+            tree.left().map(|left| left.value()),
+            Some(Range { start: 0, end: 5 })
+        );
+        assert!(tree.right().is_some_and(|right| right.left_is_none()));
     }
 
     #[test]
@@ -313,7 +385,6 @@ mod tests {
     #[test]
     fn test_deep_tree() {
         let mut tree = RangedBinaryTree::new(Range { start: 50, end: 60 });
-        // Zig Zag
         tree.insert(Range { start: 25, end: 30 });
         tree.insert(Range { start: 10, end: 15 });
         tree.insert(Range { start: 75, end: 80 });
@@ -325,29 +396,18 @@ mod tests {
         assert!(tree.search(12));
         assert!(tree.search(105));
         assert!(!tree.search(40));
+        // rebalanced: 10-15 rotates 50-60 down to the right, 100-110 then lifts 75-80
+        let leaf = |start, end| Some(Box::new(RangedBinaryTree::new(Range { start, end })));
         let expected = RangedBinaryTree {
-            value: Range { start: 50, end: 60 },
-            left: Some(Box::new(RangedBinaryTree {
-                value: Range { start: 25, end: 30 },
-                left: Some(Box::new(RangedBinaryTree {
-                    value: Range { start: 10, end: 15 },
-                    left: None,
-                    right: None,
-                })),
-                right: None,
-            })),
+            value: Range { start: 25, end: 30 },
+            left: leaf(10, 15),
             right: Some(Box::new(RangedBinaryTree {
                 value: Range { start: 75, end: 80 },
-                left: None,
-                right: Some(Box::new(RangedBinaryTree {
-                    value: Range {
-                        start: 100,
-                        end: 110,
-                    },
-                    left: None,
-                    right: None,
-                })),
+                left: leaf(50, 60),
+                right: leaf(100, 110),
+                height: 2,
             })),
+            height: 3,
         };
         assert_eq!(tree, expected);
         assert_eq!(tree.total(), 40); // 11 + 6 + 6 + 6 + 11
@@ -363,6 +423,7 @@ mod tests {
             value: Range { start: 10, end: 25 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(
             tree, expected,
@@ -383,6 +444,7 @@ mod tests {
             value: Range { start: 5, end: 20 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(
             tree, expected,
@@ -401,6 +463,7 @@ mod tests {
             value: Range { start: 10, end: 30 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(
             tree, expected,
@@ -419,6 +482,7 @@ mod tests {
             value: Range { start: 10, end: 30 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(
             tree, expected,
@@ -438,6 +502,7 @@ mod tests {
             value: Range { start: 10, end: 30 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(tree, expected, "Tree should merge touching ranges");
         assert_eq!(tree.total(), 21);
@@ -452,6 +517,7 @@ mod tests {
             value: Range { start: 5, end: 20 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(tree, expected, "Tree should merge touching ranges");
         assert_eq!(tree.total(), 16);
@@ -470,7 +536,9 @@ mod tests {
                 value: Range { start: 30, end: 40 },
                 left: None,
                 right: None,
+                height: 1,
             })),
+            height: 2,
         };
         assert_eq!(
             tree, expected,
@@ -493,6 +561,7 @@ mod tests {
             value: Range { start: 0, end: 30 },
             left: None,
             right: None,
+            height: 1,
         };
         assert_eq!(
             tree, expected,
@@ -713,17 +782,82 @@ mod tests {
             "Tree failed to collapse multiple levels into single range: {tree}"
         );
 
-        // tree.insert(Range { start: 40, end: 70 });
-        // // Structure:
-        // //       [10, 70]
-        // //               \
-        // //              [90, 100]
-        // //                /
-        // //            [40, 80]
-        // assert_eq!(
-        //     tree.total(),
-        //     64,
-        //     "Tree failed to collapse multiple levels into single range: {tree}"
-        // );
+        tree.insert(Range { start: 40, end: 70 });
+        // Structure:
+        //       [10, 80]
+        //               \
+        //              [90, 100]
+        assert_eq!(
+            tree.total(),
+            82,
+            "Tree failed to collapse multiple levels into single range: {tree}"
+        );
+        assert!(tree.search(75));
+        assert!(!tree.search(85));
+    }
+
+    fn check_balanced(tree: &RangedBinaryTree) -> usize {
+        let left = tree.left.as_deref().map_or(0, check_balanced);
+        let right = tree.right.as_deref().map_or(0, check_balanced);
+        assert!(left.abs_diff(right) <= 1, "unbalanced at {}", tree.value);
+        assert_eq!(tree.height, left.max(right) + 1);
+        tree.height
+    }
+
+    #[test]
+    fn test_sorted_input_stays_logarithmic() {
+        let ranges = (0..4096)
+            .map(|i| Range {
+                start: 10 * i,
+                end: 10 * i + 5,
+            })
+            .collect::<Vec<_>>();
+        let tree = RangedBinaryTree::from(&ranges);
+        // an AVL tree of n nodes is at most 1.44 log2(n) high
+        assert!(check_balanced(&tree) <= 18);
+        assert_eq!(tree.total(), 4096 * 6);
+        assert!(tree.search(40955));
+        assert!(!tree.search(40956));
+
+        let reversed = ranges.iter().rev().copied().collect::<Vec<_>>();
+        assert!(check_balanced(&RangedBinaryTree::from(&reversed)) <= 18);
+    }
+
+    #[test]
+    fn test_random_merges_match_interval_set() {
+        let mut rng = crate::rng::Rng::new(5);
+        for _ in 0..50 {
+            let ranges = (0..200)
+                .map(|_| {
+                    let start = rng.range(0..=2000);
+                    Range {
+                        start,
+                        end: start + rng.range(0..=40),
+                    }
+                })
+                .collect::<Vec<_>>();
+            let tree = RangedBinaryTree::from(&ranges);
+            let set = IntervalSet::from(ranges.as_slice());
+            check_balanced(&tree);
+            assert_eq!(tree.total(), set.total());
+            for value in 0..=2050 {
+                assert_eq!(tree.search(value), set.contains(value), "{}", value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_range_errors() {
+        let source = "1-5,7,9-3";
+        let ranges = source.split(',').collect::<Vec<_>>();
+        assert_eq!(
+            create_range(source, ranges[0]),
+            Ok(Range { start: 1, end: 5 })
+        );
+        let err = create_range(source, ranges[1]).unwrap_err();
+        assert_eq!(err.column, 5);
+        let err = make_ranges("1-5, 9-3", ',').unwrap_err();
+        assert_eq!(err.column, 6);
+        assert!(err.message.contains("after its end"));
     }
 }
@@ -0,0 +1,265 @@
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::parse::{ParseError, parse_token};
+
+/// Integer type usable as a [`Point`] coordinate.
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + FromStr<Err = ParseIntError>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self;
+}
+
+/// Coordinate that can be negative, needed for directions.
+pub trait SignedCoord: Coord + Neg<Output = Self> {
+    const MINUS_ONE: Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_diff(self, other: Self) -> Self {
+                if self > other { self - other } else { other - self }
+            }
+        })*
+    };
+}
+
+macro_rules! impl_signed_coord {
+    ($($t:ty),*) => {
+        $(impl SignedCoord for $t {
+            const MINUS_ONE: Self = -1;
+        })*
+    };
+}
+
+impl_coord!(i32, i64, isize, u32, u64, usize);
+impl_signed_coord!(i32, i64, isize);
+
+/// Point with `N` integer coordinates, written and parsed as `x,y[,z]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T, const N: usize>(pub [T; N]);
+
+pub type Point2<T> = Point<T, 2>;
+pub type Point3<T> = Point<T, 3>;
+
+impl<T: Coord, const N: usize> Point<T, N> {
+    pub const ORIGIN: Self = Point([T::ZERO; N]);
+
+    /// Sum of the absolute coordinate differences.
+    pub fn manhattan(&self, other: &Self) -> T {
+        (0..N).fold(T::ZERO, |acc, i| acc + self.0[i].abs_diff(other.0[i]))
+    }
+
+    /// Largest absolute coordinate difference.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (0..N).fold(T::ZERO, |acc, i| acc.max(self.0[i].abs_diff(other.0[i])))
+    }
+
+    /// Exact squared Euclidean distance.
+    pub fn distance_squared(&self, other: &Self) -> T {
+        (0..N).fold(T::ZERO, |acc, i| {
+            let diff = self.0[i].abs_diff(other.0[i]);
+            acc + diff * diff
+        })
+    }
+
+    /// Parses `N` comma separated coordinates from `token`, a slice of `source`.
+    pub fn parse(source: &str, token: &str) -> Result<Self, ParseError> {
+        let coords = token
+            .split(',')
+            .map(|coord| parse_token::<T>(source, coord.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let found = coords.len();
+        let coords = coords.try_into().map_err(|_| {
+            ParseError::at(
+                source,
+                token,
+                format!("expected {} coordinates, found {}", N, found),
+            )
+        })?;
+        Ok(Point(coords))
+    }
+}
+
+impl<T: Coord> Point<T, 2> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point([x, y])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Coord> Point<T, 3> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point([x, y, z])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+/// Unit steps in the plane. `y` grows downwards like the rows of the puzzle inputs.
+impl<T: SignedCoord> Point<T, 2> {
+    pub const UP: Self = Point([T::ZERO, T::MINUS_ONE]);
+    pub const DOWN: Self = Point([T::ZERO, T::ONE]);
+    pub const LEFT: Self = Point([T::MINUS_ONE, T::ZERO]);
+    pub const RIGHT: Self = Point([T::ONE, T::ZERO]);
+    pub const UP_LEFT: Self = Point([T::MINUS_ONE, T::MINUS_ONE]);
+    pub const UP_RIGHT: Self = Point([T::ONE, T::MINUS_ONE]);
+    pub const DOWN_LEFT: Self = Point([T::MINUS_ONE, T::ONE]);
+    pub const DOWN_RIGHT: Self = Point([T::ONE, T::ONE]);
+
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+    pub const ALL_DIRECTIONS: [Self; 8] = [
+        Self::UP,
+        Self::UP_RIGHT,
+        Self::RIGHT,
+        Self::DOWN_RIGHT,
+        Self::DOWN,
+        Self::DOWN_LEFT,
+        Self::LEFT,
+        Self::UP_LEFT,
+    ];
+
+    /// Quarter turn clockwise (with `y` downwards).
+    pub fn turn_right(self) -> Self {
+        Point([-self.0[1], self.0[0]])
+    }
+
+    pub fn turn_left(self) -> Self {
+        Point([self.0[1], -self.0[0]])
+    }
+}
+
+impl<T: Coord, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<T: Coord, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<T: Coord, const N: usize> Mul<T> for Point<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point(self.0.map(|coord| coord * rhs))
+    }
+}
+
+impl<T: SignedCoord, const N: usize> Neg for Point<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point(self.0.map(|coord| -coord))
+    }
+}
+
+impl<T: Coord, const N: usize> AddAssign for Point<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord, const N: usize> SubAssign for Point<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coord, const N: usize> FromStr for Point<T, N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Point::parse(s, s)
+    }
+}
+
+impl<T: Coord, const N: usize> Display for Point<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coords = self.0.iter().map(T::to_string).collect::<Vec<_>>();
+        write!(f, "{}", coords.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(3i64, -4);
+        let b = Point2::new(-1, 2);
+        assert_eq!(a + b, Point2::new(2, -2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(b * 3, Point2::new(-3, 6));
+        assert_eq!(-a, Point2::new(-3, 4));
+        let mut c = Point2::<i64>::ORIGIN;
+        c += Point2::RIGHT;
+        c -= Point2::UP;
+        assert_eq!(c, Point2::DOWN_RIGHT);
+        assert_eq!(Point2::<i32>::UP.turn_right(), Point2::RIGHT);
+        assert_eq!(Point2::<i32>::UP.turn_left(), Point2::LEFT);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3::new(1usize, 5, 2);
+        let b = Point3::new(4, 1, 2);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert_eq!(b.distance_squared(&a), 25);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("7, -2".parse::<Point2<i32>>().unwrap(), Point2::new(7, -2));
+        assert_eq!("1,2,3".parse::<Point3<u64>>().unwrap().to_string(), "1,2,3");
+        let err = "1,2".parse::<Point3<u64>>().unwrap_err();
+        assert_eq!(err.message, "expected 3 coordinates, found 2");
+        let err = "1,x".parse::<Point2<u64>>().unwrap_err();
+        assert_eq!(err.column, 3);
+    }
+}
@@ -0,0 +1,37 @@
+use anyhow::{Result, bail};
+use std::{fs, thread, time::Duration};
+
+use advent_of_code_2025::input::InputSource;
+use advent_of_code_2025::output::{self, Format};
+use advent_of_code_2025::runner;
+use advent_of_code_2025::solution::{Day, Part};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Re-runs the part every time the input file is modified. Runs until interrupted.
+pub fn watch(
+    day: &'static Day,
+    part: Part,
+    source: &InputSource,
+    params: &[(String, String)],
+    format: Format,
+) -> Result<()> {
+    if matches!(source, InputSource::Stdin(_)) {
+        bail!("stdin cannot be watched, use --input with a file");
+    }
+    let path = source.resolve(day, part)?;
+    let mut last_modified = None;
+    loop {
+        // A missing file (e.g. while an editor replaces it) is simply retried.
+        let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+        if modified.is_some() && modified != last_modified {
+            last_modified = modified;
+            let result = runner::run(day, part, source, params);
+            print!("\x1b[2J\x1b[H");
+            output::print_result(&result, format);
+            println!("took {:.3?}", result.elapsed);
+            println!("\nwatching {} (Ctrl+C to stop)", path.display());
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
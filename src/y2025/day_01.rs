@@ -0,0 +1,180 @@
+use std::path::Path;
+
+use crate::parse::{ParseError, parse_file, parse_token};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Part, Solution};
+use anyhow::{Result, ensure};
+use tracing::{debug, trace};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Instruction>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let instructions = load_instructions(data_path)?;
+        debug!(rotations = instructions.len(), "parsed rotations");
+        Ok(instructions)
+    }
+
+    fn part_one(instructions: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(instructions)
+    }
+
+    fn part_two(instructions: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(instructions)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "count",
+            default: "4000",
+            input_defaults: &[("crosscheck", "20")],
+        },
+        Param {
+            name: "max_value",
+            default: "999",
+            input_defaults: &[("crosscheck", "250")],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_instructions(rng, knobs.param("count")?, knobs.param("max_value")?)
+    }
+
+    const HAS_REFERENCE: bool = true;
+
+    fn reference(instructions: &Self::Input, part: Part, _ctx: &Context) -> Result<Answer> {
+        Ok(simulate_clicks(instructions, part))
+    }
+}
+
+pub fn task_01(instructions: &[Instruction]) -> Result<Answer> {
+    let mut curr = 50; // starting point
+    let mut zeros_hit = 0;
+    for instruction in instructions {
+        let value = instruction.value % 100;
+        curr = match instruction.rot {
+            Rotation::L => match curr < value {
+                true => 100 - ((100 + value - curr) % 100),
+                false => curr - value,
+            },
+            Rotation::R => (curr + value) % 100,
+        };
+        assert!(curr < 100);
+        if curr == 0 {
+            zeros_hit += 1;
+        }
+        trace!(?instruction, position = curr, zeros = zeros_hit, "rotated");
+    }
+    Ok(Answer::new(zeros_hit).with("final_position", curr))
+}
+
+pub fn task_02(instructions: &[Instruction]) -> Result<Answer> {
+    let mut curr = 50; // starting point
+    let mut zeros_hit = 0;
+    for instruction in instructions {
+        zeros_hit += instruction.value / 100;
+        let value = instruction.value % 100;
+        let new = match instruction.rot {
+            Rotation::L => match curr < value {
+                true => {
+                    if curr != 0 {
+                        zeros_hit += 1;
+                    }
+                    100 - ((100 + value - curr) % 100)
+                }
+                false => curr - value,
+            },
+            Rotation::R => curr + value,
+        };
+        // landing on 100 is counted below as the dial stopping at zero
+        let wraps = if new != 100 { new / 100 } else { 0 };
+        zeros_hit += wraps;
+        curr = new % 100;
+        assert!(curr < 100);
+        if curr == 0 {
+            zeros_hit += 1;
+        }
+        trace!(
+            ?instruction,
+            wraps,
+            position = curr,
+            zeros = zeros_hit,
+            "rotated"
+        );
+    }
+    Ok(Answer::new(zeros_hit).with("final_position", curr))
+}
+
+/// Turns the dial one click at a time, counting the zeros seen after every rotation
+/// (part one) or after every click (part two).
+fn simulate_clicks(instructions: &[Instruction], part: Part) -> Answer {
+    let mut curr = 50;
+    let mut zeros_hit = 0;
+    for instruction in instructions {
+        for _ in 0..instruction.value {
+            curr = match instruction.rot {
+                Rotation::L => (curr + 99) % 100,
+                Rotation::R => (curr + 1) % 100,
+            };
+            if part == Part::Two && curr == 0 {
+                zeros_hit += 1;
+            }
+        }
+        if part == Part::One && curr == 0 {
+            zeros_hit += 1;
+        }
+    }
+    Answer::new(zeros_hit).with("final_position", curr)
+}
+
+#[derive(Debug)]
+pub enum Rotation {
+    L,
+    R,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub rot: Rotation,
+    pub value: u32,
+}
+
+/// One rotation per line, its direction `L` or `R` followed by the number of clicks.
+pub fn load_instructions(data_path: &Path) -> Result<Vec<Instruction>> {
+    parse_file(data_path, |content| {
+        content
+            .lines()
+            .map(|line| line.trim())
+            .map(|line| {
+                let rot = match line.chars().next() {
+                    Some('L') => Rotation::L,
+                    Some('R') => Rotation::R,
+                    _ => {
+                        return Err(ParseError::at(
+                            content,
+                            line,
+                            "expected a rotation 'L' or 'R'",
+                        ));
+                    }
+                };
+                let value = parse_token(content, &line[1..])?;
+                Ok(Instruction { rot, value })
+            })
+            .collect()
+    })
+}
+
+/// Random rotations of 1 to `max_value` clicks in either direction.
+pub fn generate_instructions(rng: &mut Rng, count: usize, max_value: usize) -> Result<String> {
+    ensure!(max_value >= 1, "max_value must be at least 1");
+    Ok((0..count)
+        .map(|_| {
+            let rot = if rng.chance(50) { 'L' } else { 'R' };
+            format!("{}{}\n", rot, rng.range(1..=max_value))
+        })
+        .collect())
+}
@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use anyhow::{Result, ensure};
+use tracing::{debug, trace};
+
+use crate::parse::parse_file;
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Part, Solution};
+use crate::utils::{Range, digit_count, make_ranges};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Range>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let ranges = load_data(data_path)?;
+        debug!(
+            ranges = ranges.len(),
+            ids = ranges
+                .iter()
+                .map(|range| range.end - range.start + 1)
+                .sum::<usize>(),
+            "parsed ID ranges"
+        );
+        Ok(ranges)
+    }
+
+    fn part_one(ranges: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(ranges)
+    }
+
+    fn part_two(ranges: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(ranges)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "count",
+            default: "30",
+            input_defaults: &[("crosscheck", "5")],
+        },
+        Param {
+            name: "max_digits",
+            default: "10",
+            input_defaults: &[("crosscheck", "6")],
+        },
+        Param {
+            name: "max_width",
+            default: "100000",
+            input_defaults: &[("crosscheck", "1000")],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_ranges(
+            rng,
+            knobs.param("count")?,
+            knobs.param("max_digits")?,
+            knobs.param("max_width")?,
+        )
+    }
+
+    const HAS_REFERENCE: bool = true;
+
+    fn reference(ranges: &Self::Input, part: Part, _ctx: &Context) -> Result<Answer> {
+        Ok(repeated_by_text(ranges, part))
+    }
+}
+
+pub fn task_01(ranges: &[Range]) -> Result<Answer> {
+    let mut palindromic = Vec::new();
+    for range in ranges {
+        for i in range.start..=range.end {
+            let digs = digit_count(i);
+            if digs % 2 == 1 {
+                continue;
+            }
+            let exp = 10usize.pow((digs / 2) as u32);
+            let left = i / exp;
+            let right = i % exp;
+            if left == right {
+                trace!(number = i, "found palindromic number");
+                palindromic.push(i);
+            }
+        }
+    }
+    Ok(Answer::new(palindromic.iter().sum::<usize>()).with("count", palindromic.len()))
+}
+
+pub fn task_02(ranges: &[Range]) -> Result<Answer> {
+    let mut palindromic = Vec::new();
+    for range in ranges {
+        for i in range.start..=range.end {
+            let digs = digit_count(i);
+            for splits in 1..=(digs / 2) {
+                if !digs.is_multiple_of(splits) {
+                    continue;
+                }
+                let exps = (splits..digs)
+                    .step_by(splits)
+                    .map(|j| 10usize.pow(j as u32))
+                    .collect::<Vec<usize>>();
+                let base = 10usize.pow(splits as u32);
+                let nums = exps
+                    .iter()
+                    .map(|exp| (i / exp) % base)
+                    .chain(std::iter::once(i % base))
+                    .collect::<Vec<usize>>();
+                if nums.iter().all(|&num| num == nums[0]) {
+                    trace!(number = i, splits, ?nums, "found palindromic number");
+                    palindromic.push(i);
+                    break;
+                }
+            }
+        }
+    }
+    Ok(Answer::new(palindromic.iter().sum::<usize>()).with("count", palindromic.len()))
+}
+
+/// Compares the digits as text: part one accepts exactly two repetitions of a sequence,
+/// part two any number of at least two.
+fn repeated_by_text(ranges: &[Range], part: Part) -> Answer {
+    let is_repeated = |digits: &str, times: usize| {
+        digits.len().is_multiple_of(times) && digits == digits[..digits.len() / times].repeat(times)
+    };
+    let mut repeated = Vec::new();
+    for range in ranges {
+        for i in range.start..=range.end {
+            let digits = i.to_string();
+            let found = match part {
+                Part::One => is_repeated(&digits, 2),
+                Part::Two => (2..=digits.len()).any(|times| is_repeated(&digits, times)),
+            };
+            if found {
+                repeated.push(i);
+            }
+        }
+    }
+    Answer::new(repeated.iter().sum::<usize>()).with("count", repeated.len())
+}
+
+pub fn load_data(data_path: &Path) -> Result<Vec<Range>> {
+    parse_file(data_path, |content| make_ranges(content, ','))
+}
+
+/// Comma separated ID ranges starting at numbers of up to `max_digits` digits.
+pub fn generate_ranges(
+    rng: &mut Rng,
+    count: usize,
+    max_digits: usize,
+    max_width: usize,
+) -> Result<String> {
+    ensure!(
+        (1..=18).contains(&max_digits),
+        "max_digits must be between 1 and 18"
+    );
+    let ranges = (0..count)
+        .map(|_| {
+            let digits = rng.range(1..=max_digits) as u32;
+            let start = rng.range(10usize.pow(digits - 1)..=10usize.pow(digits) - 1);
+            format!("{}-{}", start, start + rng.range(0..=max_width))
+        })
+        .collect::<Vec<_>>();
+    Ok(ranges.join(",") + "\n")
+}
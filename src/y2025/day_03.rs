@@ -0,0 +1,194 @@
+use anyhow::{Result, ensure};
+use std::path::Path;
+use tracing::{debug, trace};
+
+use crate::parse::{ParseError, parse_file};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<usize>>;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "digit_count",
+        default: "12",
+        input_defaults: &[],
+    }];
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let banks = load_banks(data_path)?;
+        debug!(
+            banks = banks.len(),
+            batteries = banks.first().map_or(0, Vec::len),
+            "parsed battery banks"
+        );
+        Ok(banks)
+    }
+
+    fn part_one(lines: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(lines)
+    }
+
+    fn part_two(lines: &Self::Input, ctx: &Context) -> Result<Answer> {
+        task_02(lines, ctx.param("digit_count")?)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "lines",
+            default: "200",
+            input_defaults: &[],
+        },
+        Param {
+            name: "length",
+            default: "100",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_banks(rng, knobs.param("lines")?, knobs.param("length")?)
+    }
+}
+
+pub fn task_01(lines: &[Vec<usize>]) -> Result<Answer> {
+    let mut biggest_nums = Vec::new();
+    for (bank, line) in lines.iter().enumerate() {
+        ensure!(line.len() >= 2, "banks need at least 2 batteries");
+        let mut biggest = 0;
+        let mut second_biggest = None;
+        for i in 0..line.len() {
+            let dig = line[i];
+            if dig > biggest && i != line.len() - 1 {
+                second_biggest = None;
+                biggest = dig;
+            } else if second_biggest.is_none() || dig > second_biggest.unwrap() {
+                second_biggest = Some(dig);
+            }
+        }
+        assert!(second_biggest.is_some());
+        let biggest_num = biggest * 10 + second_biggest.unwrap();
+        trace!(bank, joltage = biggest_num, "picked batteries");
+        biggest_nums.push(biggest_num);
+    }
+    Ok(Answer::new(biggest_nums.iter().sum::<usize>()))
+}
+
+pub fn task_02(lines: &[Vec<usize>], digit_count: usize) -> Result<Answer> {
+    ensure!(digit_count >= 2, "digit_count must be at least 2");
+    let mut biggest_nums = Vec::new();
+    for (bank, line) in lines.iter().enumerate() {
+        ensure!(
+            line.len() >= digit_count,
+            "banks need at least {} batteries",
+            digit_count
+        );
+        let mut biggest = 0;
+        let mut next_biggest = vec![None; digit_count - 1];
+        for i in 0..line.len() {
+            let dig = line[i];
+            if dig > biggest && i <= line.len() - digit_count {
+                next_biggest = vec![None; digit_count - 1];
+                biggest = dig;
+            } else {
+                let start = match digit_count - 1 > line.len() - i {
+                    true => digit_count - (line.len() - i) - 1,
+                    false => 0,
+                };
+                let tmp = start..digit_count - 1;
+                for j in tmp {
+                    match &next_biggest[j] {
+                        Some(num) => {
+                            if dig > *num && digit_count - j < line.len() {
+                                next_biggest[j] = Some(dig);
+                                next_biggest[j + 1..].iter_mut().for_each(|n| *n = None);
+                                break;
+                            }
+                        }
+                        None => {
+                            next_biggest[j] = Some(dig);
+                            next_biggest[j + 1..].iter_mut().for_each(|n| *n = None);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        // Verification
+        assert!(next_biggest.iter().all(|n| n.is_some()));
+        let mut biggest_matched = false;
+        let mut next_matched = vec![false; digit_count - 1];
+        let mut pos = 0;
+        for &dig in line {
+            if dig == biggest && !biggest_matched {
+                biggest_matched = true;
+            } else if biggest_matched && dig == next_biggest[pos].unwrap() {
+                next_matched[pos] = true;
+                pos += 1;
+                if pos >= digit_count - 1 {
+                    break;
+                }
+            }
+        }
+        assert!(
+            biggest_matched,
+            "Biggest digit {} not matched for line: {:?}",
+            biggest, line
+        );
+        assert!(
+            next_matched.iter().all(|&m| m),
+            "Not all next biggest digits matched for line: {:?}, next_biggest: {:?} found matches: {:?}",
+            line,
+            next_biggest,
+            next_matched
+        );
+        let biggest_num = biggest * 10usize.pow((digit_count - 1) as u32)
+            + next_biggest
+                .iter()
+                .map(|n| n.unwrap())
+                .fold(0, |acc, n| acc * 10 + n);
+        trace!(bank, joltage = biggest_num, "picked batteries");
+        biggest_nums.push(biggest_num);
+    }
+    Ok(Answer::new(biggest_nums.iter().sum::<usize>()).with("digit_count", digit_count))
+}
+
+/// One bank of battery joltages per line, each a single digit.
+pub fn load_banks(data_path: &Path) -> Result<Vec<Vec<usize>>> {
+    parse_file(data_path, |content| {
+        content
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(pos, c)| {
+                        c.to_digit(10).map(|dig| dig as usize).ok_or_else(|| {
+                            ParseError::at(
+                                content,
+                                &line[pos..pos + c.len_utf8()],
+                                format!("expected a digit, found '{}'", c),
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+/// Lines of `length` random digits from 1 to 9.
+pub fn generate_banks(rng: &mut Rng, lines: usize, length: usize) -> Result<String> {
+    ensure!(length >= 2, "length must be at least 2");
+    Ok((0..lines)
+        .map(|_| {
+            let mut line = (0..length)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect())
+}
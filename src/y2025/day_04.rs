@@ -0,0 +1,138 @@
+use anyhow::{Result, ensure};
+use std::fmt::{self, Display};
+use std::path::Path;
+use tracing::{debug, trace};
+
+use crate::parse::parse_file;
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Solution};
+use crate::utils::{Grid, Point2};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<Cell>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let map = load_map(data_path)?;
+        debug!(width = map.width(), height = map.height(), "parsed map");
+        Ok(map)
+    }
+
+    fn part_one(map: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(map)
+    }
+
+    fn part_two(map: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(map)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "rows",
+            default: "140",
+            input_defaults: &[],
+        },
+        Param {
+            name: "cols",
+            default: "140",
+            input_defaults: &[],
+        },
+        Param {
+            name: "density",
+            default: "60",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_map(
+            rng,
+            knobs.param("rows")?,
+            knobs.param("cols")?,
+            knobs.param("density")?,
+        )
+    }
+}
+
+pub fn task_01(map: &Grid<Cell>) -> Result<Answer> {
+    let valid_cells = find_valid_cells(map);
+    Ok(Answer::new(valid_cells.len()))
+}
+
+fn find_valid_cells(map: &Grid<Cell>) -> Vec<Point2<usize>> {
+    map.iter()
+        .filter(|(pos, cell)| {
+            **cell == Cell::Filled
+                && map
+                    .neighbors8(*pos)
+                    .filter(|&adjacent| map[adjacent] == Cell::Filled)
+                    .count()
+                    < 4
+        })
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub fn task_02(map: &Grid<Cell>) -> Result<Answer> {
+    let mut map = map.clone();
+    let mut removed_cells = 0;
+    loop {
+        let valid_cells = find_valid_cells(&map);
+        if valid_cells.is_empty() {
+            break;
+        }
+        for pos in valid_cells.iter() {
+            map[*pos] = Cell::Empty;
+        }
+        removed_cells += valid_cells.len();
+        trace!(
+            removed = valid_cells.len(),
+            total = removed_cells,
+            "removed rolls"
+        );
+    }
+
+    Ok(Answer::new(removed_cells))
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Cell {
+    Empty,
+    Filled,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::Filled => write!(f, "@"),
+        }
+    }
+}
+
+pub fn load_map(data_path: &Path) -> Result<Grid<Cell>> {
+    parse_file(data_path, |content| {
+        Grid::parse(content, |c| match c {
+            '.' => Some(Cell::Empty),
+            '@' => Some(Cell::Filled),
+            _ => None,
+        })
+    })
+}
+
+/// A `rows`×`cols` grid where each cell holds a roll of paper with `density` percent chance.
+pub fn generate_map(rng: &mut Rng, rows: usize, cols: usize, density: usize) -> Result<String> {
+    ensure!(density <= 100, "density is a percentage");
+    Ok((0..rows)
+        .map(|_| {
+            let mut line = (0..cols)
+                .map(|_| if rng.chance(density) { '@' } else { '.' })
+                .collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect())
+}
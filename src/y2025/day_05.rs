@@ -0,0 +1,171 @@
+use anyhow::{Result, ensure};
+use std::path::Path;
+use tracing::{debug, trace};
+
+use crate::parse::{parse_file, parse_token};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Part, Solution};
+use crate::utils::{IntervalSet, Range, create_range};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Storage;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let storage = load_storage(data_path)?;
+        debug!(
+            ranges = storage.fresh.len(),
+            available = storage.available.len(),
+            "parsed inventory"
+        );
+        Ok(storage)
+    }
+
+    fn part_one(storage: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(storage)
+    }
+
+    fn part_two(storage: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(storage)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "ranges",
+            default: "180",
+            input_defaults: &[("crosscheck", "6")],
+        },
+        Param {
+            name: "ids",
+            default: "1000",
+            input_defaults: &[("crosscheck", "20")],
+        },
+        Param {
+            name: "max_value",
+            default: "500000000000000",
+            input_defaults: &[("crosscheck", "100")],
+        },
+        Param {
+            name: "max_width",
+            default: "10000000000000",
+            input_defaults: &[("crosscheck", "20")],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_storage(
+            rng,
+            knobs.param("ranges")?,
+            knobs.param("ids")?,
+            knobs.param("max_value")?,
+            knobs.param("max_width")?,
+        )
+    }
+
+    const HAS_REFERENCE: bool = true;
+
+    fn reference(storage: &Self::Input, part: Part, _ctx: &Context) -> Result<Answer> {
+        Ok(count_by_scan(storage, part))
+    }
+}
+
+pub fn task_01(storage: &Storage) -> Result<Answer> {
+    let fresh = IntervalSet::from(storage.fresh.as_slice());
+    let total_available: usize = storage
+        .available
+        .iter()
+        .filter(|&&id| {
+            let is_fresh = fresh.contains(id);
+            trace!(id, fresh = is_fresh, "checked ID");
+            is_fresh
+        })
+        .count();
+
+    Ok(Answer::new(total_available))
+}
+
+pub fn task_02(storage: &Storage) -> Result<Answer> {
+    let fresh = IntervalSet::from(storage.fresh.as_slice());
+    for range in fresh.iter() {
+        trace!(%range, "merged fresh range");
+    }
+    Ok(Answer::new(fresh.total()))
+}
+
+/// Checks every available ID against every range (part one) and counts the fresh IDs
+/// by walking the sorted ranges (part two).
+fn count_by_scan(storage: &Storage, part: Part) -> Answer {
+    match part {
+        Part::One => Answer::new(
+            storage
+                .available
+                .iter()
+                .filter(|&&value| storage.fresh.iter().any(|range| range.contains(value)))
+                .count(),
+        ),
+        Part::Two => {
+            let mut ranges = storage.fresh.clone();
+            ranges.sort_by_key(|range| range.start);
+            let mut total = 0;
+            let mut next = 0;
+            for range in ranges {
+                let start = range.start.max(next);
+                if start <= range.end {
+                    total += range.end - start + 1;
+                    next = range.end + 1;
+                }
+            }
+            Answer::new(total)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Storage {
+    pub fresh: Vec<Range>,
+    pub available: Vec<usize>,
+}
+
+pub fn load_storage(data_path: &Path) -> Result<Storage> {
+    parse_file(data_path, |content| {
+        let mut fresh = Vec::new();
+        let mut available = Vec::new();
+
+        let mut split_happened = false;
+        for line in content.lines() {
+            match line.is_empty() {
+                true => split_happened = true,
+                false => match split_happened {
+                    true => available.push(parse_token::<usize>(content, line)?),
+                    false => fresh.push(create_range(content, line)?),
+                },
+            }
+        }
+        Ok(Storage { fresh, available })
+    })
+}
+
+/// Fresh ID ranges, a blank line and the available IDs, all at most `max_value`.
+pub fn generate_storage(
+    rng: &mut Rng,
+    ranges: usize,
+    ids: usize,
+    max_value: usize,
+    max_width: usize,
+) -> Result<String> {
+    ensure!(max_value >= 1, "max_value must be at least 1");
+    let mut content = String::new();
+    for _ in 0..ranges {
+        let start = rng.range(1..=max_value);
+        let end = (start + rng.range(0..=max_width)).min(max_value);
+        content += &format!("{}-{}\n", start, end);
+    }
+    content.push('\n');
+    for _ in 0..ids {
+        content += &format!("{}\n", rng.range(1..=max_value));
+    }
+    Ok(content)
+}
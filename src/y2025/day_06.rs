@@ -0,0 +1,238 @@
+use anyhow::{Result, ensure};
+use std::path::Path;
+use tracing::{debug, trace};
+
+use crate::parse::{ParseError, parse_file, parse_token};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Worksheet;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let worksheet = load_worksheet(data_path)?;
+        debug!(
+            problems = worksheet.ops.len(),
+            rows = worksheet.numbers.len(),
+            "parsed worksheet"
+        );
+        Ok(worksheet)
+    }
+
+    fn part_one(worksheet: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(worksheet)
+    }
+
+    fn part_two(worksheet: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(worksheet)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "problems",
+            default: "1000",
+            input_defaults: &[],
+        },
+        Param {
+            name: "rows",
+            default: "4",
+            input_defaults: &[],
+        },
+        Param {
+            name: "max_digits",
+            default: "4",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_worksheet(
+            rng,
+            knobs.param("problems")?,
+            knobs.param("rows")?,
+            knobs.param("max_digits")?,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    fn identity(self) -> usize {
+        match self {
+            Op::Add => 0,
+            Op::Mul => 1,
+        }
+    }
+
+    fn apply(self, acc: usize, val: usize) -> usize {
+        match self {
+            Op::Add => acc + val,
+            Op::Mul => acc * val,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Worksheet {
+    /// Numbers of every row, read left to right.
+    numbers: Vec<Vec<usize>>,
+    /// The number rows as written, their digit columns are read top to bottom in part two.
+    rows: Vec<String>,
+    /// Operator of every problem with the column it is written in, which is also the
+    /// first column of the problem.
+    ops: Vec<(usize, Op)>,
+}
+
+pub fn task_01(worksheet: &Worksheet) -> Result<Answer> {
+    let mut grand_total = 0;
+    for (col, &(_, op)) in worksheet.ops.iter().enumerate() {
+        let result = worksheet
+            .numbers
+            .iter()
+            .fold(op.identity(), |acc, row| op.apply(acc, row[col]));
+        trace!(problem = col, ?op, result, "solved problem");
+        grand_total += result;
+    }
+    Ok(Answer::new(grand_total))
+}
+
+pub fn task_02(worksheet: &Worksheet) -> Result<Answer> {
+    let width = worksheet.rows.iter().map(String::len).max().unwrap_or(0);
+    let mut grand_total = 0;
+    for (idx, &(start, op)) in worksheet.ops.iter().enumerate() {
+        let end = worksheet.ops.get(idx + 1).map_or(width, |&(next, _)| next);
+        let mut col_total = op.identity();
+        for col in start..end {
+            // rows only hold digits and spaces, so bytes are characters
+            let digits = worksheet
+                .rows
+                .iter()
+                .filter_map(|row| row.as_bytes().get(col))
+                .filter(|c| c.is_ascii_digit())
+                .map(|c| (c - b'0') as usize)
+                .collect::<Vec<_>>();
+            if !digits.is_empty() {
+                col_total = op.apply(col_total, digits.iter().fold(0, |acc, d| acc * 10 + d));
+            }
+        }
+        trace!(problem = idx, ?op, result = col_total, "solved problem");
+        grand_total += col_total;
+    }
+    Ok(Answer::new(grand_total))
+}
+
+/// Rows of numbers aligned in columns, followed by a row with the operator of every
+/// column of numbers.
+pub fn load_worksheet(data_path: &Path) -> Result<Worksheet> {
+    parse_file(data_path, |content| {
+        let lines = content.lines().collect::<Vec<_>>();
+        let Some((op_line, number_lines)) = lines.split_last() else {
+            return Err(ParseError::at_offset(content, 0, "empty worksheet"));
+        };
+        let mut ops = Vec::new();
+        for (pos, c) in op_line.char_indices() {
+            let op = match c {
+                ' ' => continue,
+                '+' => Op::Add,
+                '*' => Op::Mul,
+                _ => {
+                    return Err(ParseError::at(
+                        content,
+                        &op_line[pos..pos + c.len_utf8()],
+                        format!("unknown operator '{}'", c),
+                    ));
+                }
+            };
+            ops.push((pos, op));
+        }
+        let mut numbers = Vec::new();
+        for line in number_lines {
+            if let Some((pos, c)) = line
+                .char_indices()
+                .find(|&(_, c)| c != ' ' && !c.is_ascii_digit())
+            {
+                return Err(ParseError::at(
+                    content,
+                    &line[pos..pos + c.len_utf8()],
+                    format!("expected a digit, found '{}'", c),
+                ));
+            }
+            let row = line
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .map(|cell| parse_token(content, cell))
+                .collect::<Result<Vec<usize>, _>>()?;
+            if row.len() != ops.len() {
+                return Err(ParseError::at(
+                    content,
+                    line,
+                    format!("expected {} numbers, found {}", ops.len(), row.len()),
+                ));
+            }
+            numbers.push(row);
+        }
+        Ok(Worksheet {
+            numbers,
+            rows: number_lines.iter().map(|line| line.to_string()).collect(),
+            ops,
+        })
+    })
+}
+
+/// `problems` columns of `rows` numbers with up to `max_digits` digits each, every column
+/// as wide as its longest number and its numbers all aligned left or all aligned right.
+pub fn generate_worksheet(
+    rng: &mut Rng,
+    problems: usize,
+    rows: usize,
+    max_digits: usize,
+) -> Result<String> {
+    ensure!(
+        rows >= 1 && max_digits >= 1,
+        "rows and max_digits must be at least 1"
+    );
+    // every result has at most rows * max_digits digits, in both parts
+    ensure!(
+        10usize
+            .checked_pow((rows * max_digits) as u32)
+            .and_then(|max| max.checked_mul(problems))
+            .is_some(),
+        "the answers would not fit, use fewer problems, rows or digits"
+    );
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..problems {
+        let numbers = (0..rows)
+            .map(|_| {
+                let digits = rng.range(1..=max_digits) as u32;
+                rng.range(10usize.pow(digits - 1)..=10usize.pow(digits) - 1)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let left = rng.chance(50);
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            match left {
+                true => line.push_str(&format!("{:<width$}", number, width = width)),
+                false => line.push_str(&format!("{:>width$}", number, width = width)),
+            }
+        }
+        let op = if rng.chance(50) { '+' } else { '*' };
+        lines[rows].push_str(&format!("{:<width$}", op, width = width));
+    }
+    // the number rows keep their padding, like in the puzzle input
+    let op_line = lines.pop().unwrap_or_default();
+    lines.push(op_line.trim_end().to_string());
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
@@ -0,0 +1,183 @@
+use anyhow::{Result, ensure};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+use tracing::{debug, trace};
+
+use crate::parse::{ParseError, parse_file};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Solution};
+use crate::utils::{Grid, Point2};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Map;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let map = load_map(data_path)?;
+        debug!(width = map.cells.width(), height = map.cells.height(), start = %map.start, "parsed map");
+        Ok(map)
+    }
+
+    fn part_one(map: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(map)
+    }
+
+    fn part_two(map: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(map)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "rows",
+            default: "142",
+            input_defaults: &[],
+        },
+        Param {
+            name: "cols",
+            default: "141",
+            input_defaults: &[],
+        },
+        Param {
+            name: "density",
+            default: "40",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_map(
+            rng,
+            knobs.param("rows")?,
+            knobs.param("cols")?,
+            knobs.param("density")?,
+        )
+    }
+}
+
+pub fn task_01(map: &Map) -> Result<Answer> {
+    let mut beans = HashSet::new();
+    let mut splits = 0;
+    beans.insert(map.start.x());
+    for (idx, row) in map.cells.rows().enumerate().skip(1) {
+        let mut new_beans = HashSet::new();
+        for bean in &beans {
+            match row[*bean] {
+                Cell::Empty | Cell::Start => {
+                    let _ = new_beans.insert(*bean);
+                }
+                Cell::Split => {
+                    splits += 1;
+                    if *bean > 0 {
+                        new_beans.insert(*bean - 1);
+                    }
+                    if *bean < row.len() - 1 {
+                        new_beans.insert(*bean + 1);
+                    }
+                }
+            };
+        }
+        beans = new_beans;
+        trace!(row = idx, beams = beans.len(), splits, "moved beams");
+    }
+    Ok(Answer::new(splits))
+}
+
+pub fn task_02(map: &Map) -> Result<Answer> {
+    let mut beans = HashMap::new();
+    beans.insert(map.start.x(), 1);
+    for (idx, row) in map.cells.rows().enumerate().skip(1) {
+        let mut new_beans = HashMap::new();
+        for (bean, count) in &beans {
+            match row[*bean] {
+                Cell::Empty | Cell::Start => {
+                    let _ = new_beans
+                        .entry(*bean)
+                        .and_modify(|counter| *counter += *count)
+                        .or_insert(*count);
+                }
+                Cell::Split => {
+                    if *bean > 0 {
+                        new_beans
+                            .entry(*bean - 1)
+                            .and_modify(|counter| *counter += *count)
+                            .or_insert(*count);
+                    }
+                    if *bean < row.len() - 1 {
+                        new_beans
+                            .entry(*bean + 1)
+                            .and_modify(|counter| *counter += *count)
+                            .or_insert(*count);
+                    }
+                }
+            };
+        }
+        beans = new_beans;
+        trace!(row = idx, beams = beans.len(), "moved beams");
+    }
+    let total_beans: usize = beans.values().sum();
+    Ok(Answer::new(total_beans))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Split,
+    Start,
+}
+
+pub struct Map {
+    cells: Grid<Cell>,
+    /// The only start, always in the first row.
+    start: Point2<usize>,
+}
+
+pub fn load_map(data_path: &Path) -> Result<Map> {
+    parse_file(data_path, |content| {
+        let cells = Grid::parse(content, |ch| match ch {
+            '.' => Some(Cell::Empty),
+            '^' => Some(Cell::Split),
+            'S' => Some(Cell::Start),
+            _ => None,
+        })?;
+        let start = cells
+            .find(&Cell::Start)
+            .ok_or_else(|| ParseError::at_offset(content, content.len(), "no start 'S' in map"))?;
+        for (row, line) in content.lines().enumerate() {
+            for (pos, _) in line.match_indices('S') {
+                if row != 0 || pos != start.x() {
+                    return Err(ParseError::at(
+                        content,
+                        &line[pos..pos + 1],
+                        "the map needs a single start 'S' in its first row",
+                    ));
+                }
+            }
+        }
+        Ok(Map { cells, start })
+    })
+}
+
+/// A `rows`×`cols` map with the start in the middle of the first row and a splitter with
+/// `density` percent chance on every cell of every other row below it.
+pub fn generate_map(rng: &mut Rng, rows: usize, cols: usize, density: usize) -> Result<String> {
+    ensure!(rows >= 1 && cols >= 1, "the map needs at least one cell");
+    ensure!(density <= 100, "density is a percentage");
+    Ok((0..rows)
+        .map(|row| {
+            let mut line = (0..cols)
+                .map(|col| match row {
+                    0 if col == cols / 2 => 'S',
+                    _ if row % 2 == 0 && row > 0 && rng.chance(density) => '^',
+                    _ => '.',
+                })
+                .collect::<String>();
+            line.push('\n');
+            line
+        })
+        .collect())
+}
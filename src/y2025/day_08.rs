@@ -0,0 +1,268 @@
+use anyhow::{Result, bail, ensure};
+use std::collections::HashSet;
+use std::path::Path;
+use std::vec;
+use tracing::{debug, trace};
+
+use crate::parse::parse_file;
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Part, Solution};
+use crate::utils::{DisjointSet, Point3};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Point3<usize>>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "num_connections",
+            default: "1000",
+            input_defaults: &[("test", "10"), ("crosscheck", "10")],
+        },
+        Param {
+            name: "num_largest",
+            default: "3",
+            input_defaults: &[],
+        },
+    ];
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let map = load_map(data_path)?;
+        debug!(boxes = map.len(), "parsed junction boxes");
+        Ok(map)
+    }
+
+    fn part_one(map: &Self::Input, ctx: &Context) -> Result<Answer> {
+        task_01(
+            map,
+            ctx.param("num_connections")?,
+            ctx.param("num_largest")?,
+        )
+    }
+
+    fn part_two(map: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(map)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "points",
+            default: "1000",
+            input_defaults: &[("crosscheck", "20")],
+        },
+        Param {
+            name: "max_coord",
+            default: "100000",
+            input_defaults: &[("crosscheck", "50")],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_points(rng, knobs.param("points")?, knobs.param("max_coord")?)
+    }
+
+    const HAS_REFERENCE: bool = true;
+
+    fn reference(map: &Self::Input, part: Part, ctx: &Context) -> Result<Answer> {
+        connect_sorted_pairs(
+            map,
+            part,
+            ctx.param("num_connections")?,
+            ctx.param("num_largest")?,
+        )
+    }
+}
+
+struct Connection {
+    node_a: usize,
+    node_b: usize,
+}
+
+fn dists(map: &[Point3<usize>]) -> Vec<Vec<usize>> {
+    let mut dists = vec![vec![0; map.len()]; map.len()];
+    for x in 0..map.len() {
+        let point_a = &map[x];
+        for y in (x + 1)..map.len() {
+            let point_b = &map[y];
+            let dist = point_a.distance_squared(point_b);
+            dists[x][y] = dist;
+            dists[y][x] = dist;
+        }
+    }
+    dists
+}
+
+#[allow(clippy::needless_range_loop)]
+fn min_dist(dists: &[Vec<usize>]) -> Connection {
+    let mut min_dist = usize::MAX;
+    let mut min_pair = Connection {
+        node_a: 0,
+        node_b: 0,
+    };
+    for x in 0..dists.len() {
+        for y in x + 1..dists.len() {
+            if dists[x][y] < min_dist {
+                min_dist = dists[x][y];
+                min_pair = Connection {
+                    node_a: x,
+                    node_b: y,
+                };
+            }
+        }
+    }
+    assert!(min_dist < usize::MAX);
+    assert!(min_dist > 0);
+    min_pair
+}
+
+pub fn task_01(
+    map: &[Point3<usize>],
+    num_connections: usize,
+    num_largest: usize,
+) -> Result<Answer> {
+    let mut dists = dists(map);
+    let mut circuits = DisjointSet::new(map.len());
+    for _ in 0..num_connections {
+        let min_pair = min_dist(&dists);
+        circuits.union(min_pair.node_a, min_pair.node_b);
+        trace!(
+            a = min_pair.node_a,
+            b = min_pair.node_b,
+            circuits = circuits.component_count(),
+            "connected junction boxes"
+        );
+        dists[min_pair.node_a][min_pair.node_b] = usize::MAX;
+        dists[min_pair.node_b][min_pair.node_a] = usize::MAX;
+    }
+    ensure!(
+        circuits.component_count() >= num_largest,
+        "only {} circuits",
+        circuits.component_count()
+    );
+    let mut circuit_sizes = circuits
+        .components()
+        .map(|circuit| circuit.len())
+        .collect::<Vec<_>>();
+    circuit_sizes.sort_by(|a, b| a.cmp(b).reverse());
+    let answer: usize = circuit_sizes.iter().take(num_largest).product();
+
+    Ok(Answer::new(answer))
+}
+
+pub fn task_02(map: &[Point3<usize>]) -> Result<Answer> {
+    let mut dists = dists(map);
+    let mut circuits = DisjointSet::new(map.len());
+    let mut curr_pair = min_dist(&dists);
+
+    while circuits.component_count() > 1 {
+        curr_pair = min_dist(&dists);
+        circuits.union(curr_pair.node_a, curr_pair.node_b);
+        trace!(
+            a = curr_pair.node_a,
+            b = curr_pair.node_b,
+            circuits = circuits.component_count(),
+            "connected junction boxes"
+        );
+        dists[curr_pair.node_a][curr_pair.node_b] = usize::MAX;
+        dists[curr_pair.node_b][curr_pair.node_a] = usize::MAX;
+    }
+    let answer = map[curr_pair.node_a].x() * map[curr_pair.node_b].x();
+    Ok(Answer::new(answer))
+}
+
+/// Connects the pairs in order of their exact squared distance, relabelling the whole
+/// circuit on every merge. Ties are broken by the indices of the boxes.
+fn connect_sorted_pairs(
+    map: &[Point3<usize>],
+    part: Part,
+    num_connections: usize,
+    num_largest: usize,
+) -> Result<Answer> {
+    if map.len() < 2 {
+        bail!("at least two junction boxes are needed");
+    }
+    let mut pairs = Vec::new();
+    for a in 0..map.len() {
+        for b in a + 1..map.len() {
+            pairs.push((map[a].distance_squared(&map[b]), a, b));
+        }
+    }
+    pairs.sort();
+    let mut circuit = (0..map.len()).collect::<Vec<_>>();
+    let mut circuits = map.len();
+    let mut connect = |a: usize, b: usize| {
+        let (from, to) = (circuit[b], circuit[a]);
+        if from != to {
+            circuit
+                .iter_mut()
+                .filter(|c| **c == from)
+                .for_each(|c| *c = to);
+            circuits -= 1;
+        }
+        circuits
+    };
+    match part {
+        Part::One => {
+            if num_connections > pairs.len() {
+                bail!("only {} pairs to connect", pairs.len());
+            }
+            for &(_, a, b) in &pairs[..num_connections] {
+                connect(a, b);
+            }
+            let mut sizes = vec![0usize; map.len()];
+            for &c in &circuit {
+                sizes[c] += 1;
+            }
+            sizes.retain(|&size| size > 0);
+            if sizes.len() < num_largest {
+                bail!("only {} circuits", sizes.len());
+            }
+            sizes.sort_by(|a, b| b.cmp(a));
+            Ok(Answer::new(
+                sizes.iter().take(num_largest).product::<usize>(),
+            ))
+        }
+        Part::Two => {
+            let &(_, a, b) = pairs
+                .iter()
+                .find(|&&(_, a, b)| connect(a, b) == 1)
+                .expect("connecting all pairs joins every box");
+            Ok(Answer::new(map[a].x() * map[b].x()))
+        }
+    }
+}
+
+pub fn load_map(data_path: &Path) -> Result<Vec<Point3<usize>>> {
+    parse_file(data_path, |content| {
+        content
+            .lines()
+            .map(|line| Point3::parse(content, line))
+            .collect()
+    })
+}
+
+/// Distinct junction boxes with coordinates up to `max_coord`.
+pub fn generate_points(rng: &mut Rng, points: usize, max_coord: usize) -> Result<String> {
+    ensure!(
+        (max_coord as u128 + 1).pow(3) >= points as u128,
+        "{} distinct points do not fit into max_coord {}",
+        points,
+        max_coord
+    );
+    let mut seen = HashSet::new();
+    let mut content = String::new();
+    while seen.len() < points {
+        let point = (
+            rng.range(0..=max_coord),
+            rng.range(0..=max_coord),
+            rng.range(0..=max_coord),
+        );
+        if seen.insert(point) {
+            content += &format!("{},{},{}\n", point.0, point.1, point.2);
+        }
+    }
+    Ok(content)
+}
@@ -0,0 +1,188 @@
+use anyhow::{Result, ensure};
+use geo::prelude::*;
+use geo::{Polygon, Rect};
+use std::{collections::BTreeSet, path::Path};
+use tracing::{debug, trace};
+
+use crate::parse::parse_file;
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Solution};
+use crate::utils::Point2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Point2<usize>>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let points = load_points(data_path)?;
+        debug!(tiles = points.len(), "parsed red tiles");
+        Ok(points)
+    }
+
+    fn part_one(points: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(points)
+    }
+
+    fn part_two(points: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(points)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "columns",
+            default: "62",
+            input_defaults: &[],
+        },
+        Param {
+            name: "max_coord",
+            default: "100000",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_polygon(rng, knobs.param("columns")?, knobs.param("max_coord")?)
+    }
+}
+
+pub fn task_01(points: &[Point2<usize>]) -> Result<Answer> {
+    let mut max_area = 0;
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let area = rectangle_area(&points[i], &points[j]);
+            if area > max_area {
+                trace!(from = %points[i], to = %points[j], area, "larger rectangle");
+                max_area = area;
+            }
+        }
+    }
+    Ok(Answer::new(max_area))
+}
+
+pub fn task_02(points: &[Point2<usize>]) -> Result<Answer> {
+    let mask = create_mask(points);
+    let mut max_area = 0;
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let rect = create_geo_rectangle(&points[i], &points[j]);
+            let x = mask.intersection(&rect);
+            if x.unsigned_area() != rect.unsigned_area() {
+                continue;
+            }
+            let area = rectangle_area(&points[i], &points[j]);
+            if area > max_area {
+                trace!(from = %points[i], to = %points[j], area, "larger rectangle inside");
+                max_area = area;
+            }
+        }
+    }
+    Ok(Answer::new(max_area))
+}
+
+fn rectangle_area(p1: &Point2<usize>, p2: &Point2<usize>) -> usize {
+    let width = p2.x().abs_diff(p1.x()) + 1;
+    let height = p2.y().abs_diff(p1.y()) + 1;
+    width * height
+}
+
+fn create_geo_rectangle(p1: &Point2<usize>, p2: &Point2<usize>) -> Polygon {
+    let (min_x, max_x) = if p1.x() < p2.x() {
+        (p1.x(), p2.x())
+    } else {
+        (p2.x(), p1.x())
+    };
+    let (min_y, max_y) = if p1.y() < p2.y() {
+        (p1.y(), p2.y())
+    } else {
+        (p2.y(), p1.y())
+    };
+    Rect::new(
+        geo::Coord {
+            x: min_x as f64,
+            y: min_y as f64,
+        },
+        geo::Coord {
+            x: max_x as f64,
+            y: max_y as f64,
+        },
+    )
+    .to_polygon()
+}
+
+fn create_mask(points: &[Point2<usize>]) -> Polygon {
+    let points = points
+        .iter()
+        .map(|point| (point.x() as f64, point.y() as f64))
+        .chain(std::iter::once((
+            points[0].x() as f64,
+            points[0].y() as f64,
+        )))
+        .collect::<Vec<_>>();
+    Polygon::new(geo::LineString::from(points), vec![])
+}
+
+pub fn load_points(data_path: &Path) -> Result<Vec<Point2<usize>>> {
+    parse_file(data_path, |content| {
+        content
+            .lines()
+            .map(|line| Point2::parse(content, line))
+            .collect()
+    })
+}
+
+/// Red tiles forming a rectilinear polygon, listed in order around it. The polygon is
+/// made of `columns` vertical strips, each overlapping the previous one, so it is simple.
+pub fn generate_polygon(rng: &mut Rng, columns: usize, max_coord: usize) -> Result<String> {
+    ensure!(columns >= 1, "columns must be at least 1");
+    ensure!(
+        max_coord > columns,
+        "max_coord must be larger than the number of columns"
+    );
+    let mut xs = BTreeSet::new();
+    while xs.len() < columns + 1 {
+        xs.insert(rng.range(1..=max_coord));
+    }
+    let xs = xs.into_iter().collect::<Vec<_>>();
+    // (low, high) bounds of each strip
+    let mut strips: Vec<(usize, usize)> = Vec::with_capacity(columns);
+    let mut attempts = 0;
+    while strips.len() < columns {
+        let strip = match strips.last() {
+            None => {
+                let low = rng.range(1..=max_coord - 1);
+                (low, rng.range(low + 1..=max_coord))
+            }
+            Some(&(prev_low, prev_high)) => {
+                let low = rng.range(1..=prev_high - 1);
+                let high = rng.range(low.max(prev_low) + 1..=max_coord);
+                if low == prev_low || high == prev_high {
+                    // a narrow previous strip may leave no valid choice, replace it
+                    attempts += 1;
+                    if attempts > 100 {
+                        strips.pop();
+                        attempts = 0;
+                    }
+                    continue;
+                }
+                (low, high)
+            }
+        };
+        strips.push(strip);
+    }
+    let mut tiles = Vec::with_capacity(4 * columns);
+    for (i, (_, high)) in strips.iter().enumerate() {
+        tiles.push((xs[i], *high));
+        tiles.push((xs[i + 1], *high));
+    }
+    for (i, (low, _)) in strips.iter().enumerate().rev() {
+        tiles.push((xs[i + 1], *low));
+        tiles.push((xs[i], *low));
+    }
+    Ok(tiles
+        .into_iter()
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect())
+}
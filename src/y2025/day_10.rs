@@ -0,0 +1,463 @@
+use anyhow::{Result, anyhow, bail, ensure};
+use good_lp::{
+    Expression, ProblemVariables, Solution as _, SolverModel, constraint, default_solver, variable,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+use tracing::{debug, trace};
+
+use crate::parse::{ParseError, parse_file, parse_token};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Part, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let instructions = load_instructions(data_path)?;
+        debug!(machines = instructions.len(), "parsed machines");
+        Ok(instructions)
+    }
+
+    fn part_one(instructions: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(instructions)
+    }
+
+    fn part_two(instructions: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(instructions)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "machines",
+            default: "150",
+            input_defaults: &[("crosscheck", "5")],
+        },
+        Param {
+            name: "max_lights",
+            default: "10",
+            input_defaults: &[("crosscheck", "4")],
+        },
+        Param {
+            name: "max_buttons",
+            default: "13",
+            input_defaults: &[("crosscheck", "5")],
+        },
+        Param {
+            name: "max_presses",
+            default: "30",
+            input_defaults: &[("crosscheck", "3")],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_machines(
+            rng,
+            knobs.param("machines")?,
+            knobs.param("max_lights")?,
+            knobs.param("max_buttons")?,
+            knobs.param("max_presses")?,
+        )
+    }
+
+    const HAS_REFERENCE: bool = true;
+
+    fn reference(instructions: &Self::Input, part: Part, _ctx: &Context) -> Result<Answer> {
+        instructions
+            .iter()
+            .map(|instruction| fewest_presses(instruction, part))
+            .sum::<Result<usize>>()
+            .map(Answer::new)
+    }
+}
+
+pub fn task_01(instructions: &[Instruction]) -> Result<Answer> {
+    let mut total_switches = 0;
+    for (i, instruction) in instructions.iter().enumerate() {
+        let mut curr_state = Grid(vec![false; instruction.grid.0.len()]);
+        let mut visited_states = HashMap::new();
+        let min_depth =
+            resolve_light_instructions(instruction, &mut curr_state, 0, &mut visited_states)
+                .ok_or_else(|| anyhow!("no buttons light up machine {}", i + 1))?;
+        trace!(machine = i + 1, presses = min_depth, "lit up machine");
+        total_switches += min_depth;
+    }
+    Ok(Answer::new(total_switches))
+}
+
+pub fn task_02(instructions: &[Instruction]) -> Result<Answer> {
+    let mut total_switches = 0;
+    for (i, instruction) in instructions.iter().enumerate() {
+        let min_depth = resolve_joltage_instructions(instruction)?;
+        debug!(
+            machine = i + 1,
+            total = instructions.len(),
+            presses = min_depth,
+            "configured joltage"
+        );
+        total_switches += min_depth;
+    }
+    Ok(Answer::new(total_switches))
+}
+
+fn resolve_light_instructions(
+    instruction: &Instruction,
+    curr_state: &mut Grid,
+    depth: usize,
+    visited_states: &mut HashMap<usize, usize>,
+) -> Option<usize> {
+    let mut min_switches = None;
+    for button in 0..instruction.buttons.len() {
+        let orig_curr_state_num = curr_state.to_num();
+        instruction.press_button(button, curr_state);
+        let curr_state_num = curr_state.to_num();
+        if instruction.grid_num == curr_state_num {
+            instruction.press_button(button, curr_state);
+            return Some(depth + 1);
+        }
+        let mut to_continue = true;
+        visited_states
+            .entry(curr_state_num)
+            .and_modify(|entry_depth| {
+                if depth < *entry_depth {
+                    *entry_depth = depth;
+                } else {
+                    to_continue = false;
+                }
+            })
+            .or_insert(depth);
+        if to_continue {
+            let res =
+                resolve_light_instructions(instruction, curr_state, depth + 1, visited_states);
+            match (res, min_switches) {
+                (Some(r), Some(m)) if r < m => {
+                    min_switches = Some(r);
+                }
+                (Some(r), None) => {
+                    min_switches = Some(r);
+                }
+                _ => {}
+            }
+        }
+        // rollback operation
+        instruction.press_button(button, curr_state);
+        assert!(curr_state.to_num() == orig_curr_state_num);
+    }
+    min_switches
+}
+
+pub fn resolve_joltage_instructions(instruction: &Instruction) -> Result<usize> {
+    let mut vars = ProblemVariables::new();
+    let x = (0..instruction.buttons.len())
+        .map(|_| vars.add(variable().min(0).integer()))
+        .collect::<Vec<_>>();
+    let objective = x.iter().fold(Expression::from(0), |acc, v| acc + v);
+    let mut constraints = Vec::new();
+    for (target_idx, target) in instruction.joltage.0.iter().enumerate() {
+        let affecting_buttons = instruction
+            .buttons
+            .iter()
+            .enumerate()
+            .filter(|(_, button)| button.switches.contains(&target_idx))
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        let expr = affecting_buttons
+            .iter()
+            .fold(Expression::from(0), |acc, &i| acc + x[i]);
+        let constraint = constraint!(Expression::from(*target as i32) == expr);
+        constraints.push(constraint);
+    }
+
+    let solution = without_stdout(|| {
+        vars.minimise(objective)
+            .using(default_solver)
+            .with_all(constraints)
+            .solve()
+    })?;
+    Ok((0..instruction.buttons.len())
+        .map(|i| solution.value(x[i]) as usize)
+        .sum())
+}
+
+/// Runs `solve` with stdout pointed at `/dev/null`. lp_solve logs every solve to stdout,
+/// which only carries the answers, and good_lp gives no access to its verbosity. Holding
+/// the stdout lock keeps other threads from printing in the meantime.
+#[cfg(unix)]
+fn without_stdout<T>(solve: impl FnOnce() -> T) -> T {
+    use std::fs::File;
+    use std::io::{self, Write};
+    use std::os::fd::AsRawFd;
+
+    let mut stdout = io::stdout().lock();
+    let _ = stdout.flush();
+    let Ok(null) = File::options().write(true).open("/dev/null") else {
+        return solve();
+    };
+    let fd = stdout.as_raw_fd();
+    // SAFETY: plain descriptor calls on stdout and a file that stays open until the end
+    let saved = unsafe { libc::dup(fd) };
+    if saved < 0 || unsafe { libc::dup2(null.as_raw_fd(), fd) } < 0 {
+        return solve();
+    }
+    let result = solve();
+    // SAFETY: flushes lp_solve's buffered C output before stdout is restored
+    unsafe {
+        libc::fflush(std::ptr::null_mut());
+        libc::dup2(saved, fd);
+        libc::close(saved);
+    }
+    result
+}
+
+#[cfg(not(unix))]
+fn without_stdout<T>(solve: impl FnOnce() -> T) -> T {
+    solve()
+}
+
+/// Breadth-first search over the light patterns (part one) or the joltage counters
+/// (part two), one button press per step.
+fn fewest_presses(instruction: &Instruction, part: Part) -> Result<usize> {
+    let target = match part {
+        Part::One => instruction.grid.0.iter().map(|&on| on as usize).collect(),
+        Part::Two => instruction.joltage.0.clone(),
+    };
+    let press = |state: &[usize], button: &Button| {
+        let mut next = state.to_vec();
+        for &switch in &button.switches {
+            next[switch] = match part {
+                Part::One => next[switch] ^ 1,
+                Part::Two => next[switch] + 1,
+            };
+        }
+        next
+    };
+    let mut frontier = vec![vec![0; target.len()]];
+    let mut seen = frontier.iter().cloned().collect::<HashSet<_>>();
+    let mut presses = 0;
+    while !frontier.is_empty() {
+        if frontier.contains(&target) {
+            return Ok(presses);
+        }
+        frontier = frontier
+            .iter()
+            .flat_map(|state| {
+                instruction
+                    .buttons
+                    .iter()
+                    .map(|button| press(state, button))
+            })
+            .filter(|next| next.iter().zip(&target).all(|(n, t)| n <= t) || part == Part::One)
+            .filter(|next| seen.insert(next.clone()))
+            .collect();
+        presses += 1;
+    }
+    bail!("the target of the machine is unreachable")
+}
+
+#[derive(Debug)]
+struct Grid(Vec<bool>);
+
+impl Grid {
+    fn to_num(&self) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(0usize, |acc, (i, &b)| acc + if b { 1 << i } else { 0 })
+    }
+}
+
+#[derive(Debug)]
+struct Button {
+    switches: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Joltage(Vec<usize>);
+
+#[derive(Debug)]
+pub struct Instruction {
+    grid: Grid,
+    /// `grid.to_num()`, the light pattern to reach.
+    grid_num: usize,
+    buttons: Vec<Button>,
+    joltage: Joltage,
+}
+
+impl Instruction {
+    /// Machine with the light pattern to reach, the lights toggled by each button and the
+    /// joltage each counter must reach.
+    pub fn new(lights: Vec<bool>, buttons: Vec<Vec<usize>>, joltage: Vec<usize>) -> Result<Self> {
+        ensure!(
+            joltage.len() == lights.len(),
+            "expected {} joltages, found {}",
+            lights.len(),
+            joltage.len()
+        );
+        if let Some(switch) = buttons.iter().flatten().find(|&&s| s >= lights.len()) {
+            bail!("button toggles light {} of {}", switch, lights.len());
+        }
+        let grid = Grid(lights);
+        Ok(Instruction {
+            grid_num: grid.to_num(),
+            grid,
+            buttons: buttons
+                .into_iter()
+                .map(|switches| Button { switches })
+                .collect(),
+            joltage: Joltage(joltage),
+        })
+    }
+
+    /// Light pattern to reach, `true` for lights that must be on.
+    pub fn lights(&self) -> &[bool] {
+        &self.grid.0
+    }
+
+    /// Lights toggled by each button.
+    pub fn buttons(&self) -> impl Iterator<Item = &[usize]> {
+        self.buttons.iter().map(|button| button.switches.as_slice())
+    }
+
+    pub fn joltage(&self) -> &[usize] {
+        &self.joltage.0
+    }
+
+    fn press_button(&self, index: usize, curr_state: &mut Grid) {
+        let button = &self.buttons[index];
+        for &switch in &button.switches {
+            curr_state.0[switch] = !curr_state.0[switch];
+        }
+    }
+}
+
+pub fn load_instructions(data_path: &Path) -> Result<Vec<Instruction>> {
+    parse_file(data_path, |content| {
+        content
+            .lines()
+            .map(|line| {
+                // parse line into Instruction
+                let splits = line.split(' ').collect::<Vec<_>>();
+                if splits.len() < 2 {
+                    return Err(ParseError::at(
+                        content,
+                        line,
+                        "expected a light grid, buttons and joltages",
+                    ));
+                }
+                let grid = enclosed(content, splits[0], '[', ']')?;
+                let buttons = &splits[1..splits.len() - 1];
+                let joltage = enclosed(content, splits[splits.len() - 1], '{', '}')?;
+                let grid = Grid(grid.chars().map(|c| c == '#').collect::<Vec<bool>>());
+                let joltage = Joltage(
+                    joltage
+                        .split(',')
+                        .map(|s| parse_token::<usize>(content, s))
+                        .collect::<Result<Vec<usize>, _>>()?,
+                );
+                let grid_num = grid.to_num();
+                Ok(Instruction {
+                    grid,
+                    grid_num,
+                    buttons: buttons
+                        .iter()
+                        .map(|b| {
+                            let switches = enclosed(content, b, '(', ')')?
+                                .split(',')
+                                .map(|s| parse_token::<usize>(content, s.trim()))
+                                .collect::<Result<Vec<usize>, _>>()?;
+                            Ok(Button { switches })
+                        })
+                        .collect::<Result<Vec<Button>, _>>()?,
+                    joltage,
+                })
+            })
+            .collect()
+    })
+}
+
+/// Strips the `open` and `close` delimiters around `token`, a slice of `source`.
+fn enclosed<'a>(
+    source: &str,
+    token: &'a str,
+    open: char,
+    close: char,
+) -> Result<&'a str, ParseError> {
+    token
+        .strip_prefix(open)
+        .and_then(|inner| inner.strip_suffix(close))
+        .ok_or_else(|| {
+            ParseError::at(
+                source,
+                token,
+                format!("expected '{}' enclosed in {}{}", token, open, close),
+            )
+        })
+}
+
+/// Machines whose light pattern and joltages are both reachable: the lights are the
+/// toggles of a random non-empty set of buttons and the joltages the counts of random
+/// numbers of presses of each button.
+pub fn generate_machines(
+    rng: &mut Rng,
+    machines: usize,
+    max_lights: usize,
+    max_buttons: usize,
+    max_presses: usize,
+) -> Result<String> {
+    ensure!(max_lights >= 1, "max_lights must be at least 1");
+    ensure!(max_buttons >= 1, "max_buttons must be at least 1");
+    let mut content = String::new();
+    for _ in 0..machines {
+        let lights = rng.range(1..=max_lights);
+        let buttons = (0..rng.range(1..=max_buttons))
+            .map(|_| {
+                let mut indices = (0..lights).collect::<Vec<_>>();
+                rng.shuffle(&mut indices);
+                indices.truncate(rng.range(1..=lights));
+                indices.sort();
+                indices
+            })
+            .collect::<Vec<_>>();
+        let mut grid = vec![false; lights];
+        while !grid.contains(&true) {
+            for button in buttons.iter().filter(|_| rng.chance(50)) {
+                for &light in button {
+                    grid[light] = !grid[light];
+                }
+            }
+        }
+        let mut joltage = vec![0; lights];
+        for button in &buttons {
+            let presses = rng.range(0..=max_presses);
+            for &light in button {
+                joltage[light] += presses;
+            }
+        }
+        let join = |values: &[usize]| {
+            values
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        content += &format!(
+            "[{}] {} {{{}}}\n",
+            grid.iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect::<String>(),
+            buttons
+                .iter()
+                .map(|button| format!("({})", join(button)))
+                .collect::<Vec<_>>()
+                .join(" "),
+            join(&joltage)
+        );
+    }
+    Ok(content)
+}
@@ -0,0 +1,221 @@
+use anyhow::{Result, ensure};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+use tracing::{debug, trace};
+
+use crate::parse::{ParseError, parse_file};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Part, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<String, Vec<String>>;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let graph = load_graph(data_path)?;
+        debug!(devices = graph.len(), "parsed devices");
+        Ok(graph)
+    }
+
+    fn part_one(graph: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(graph)
+    }
+
+    fn part_two(graph: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_02(graph)
+    }
+
+    fn test_input(part: Part) -> &'static str {
+        match part {
+            Part::One => "test-01",
+            Part::Two => "test-02",
+        }
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "layers",
+            default: "40",
+            input_defaults: &[],
+        },
+        Param {
+            name: "width",
+            default: "15",
+            input_defaults: &[],
+        },
+        Param {
+            name: "max_outputs",
+            default: "3",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_graph(
+            rng,
+            knobs.param("layers")?,
+            knobs.param("width")?,
+            knobs.param("max_outputs")?,
+        )
+    }
+}
+
+pub fn task_01(graph: &HashMap<String, Vec<String>>) -> Result<Answer> {
+    let mut cache = HashMap::new();
+    let total_paths = count_paths(graph, "you", "out", &mut cache);
+    Ok(Answer::new(total_paths))
+}
+
+pub fn task_02(graph: &HashMap<String, Vec<String>>) -> Result<Answer> {
+    let mut cache = HashMap::new();
+    let paths_fft2dac = count_paths(graph, "fft", "dac", &mut cache);
+    let (x1, x2, seg_b) = if paths_fft2dac > 0 {
+        ("fft".to_string(), "dac".to_string(), paths_fft2dac)
+    } else {
+        let paths_dac2fft = count_paths(graph, "dac", "fft", &mut cache);
+        ensure!(paths_dac2fft > 0, "no path connects fft and dac");
+        ("dac".to_string(), "fft".to_string(), paths_dac2fft)
+    };
+    let seg_a = count_paths(graph, "svr", &x1, &mut cache);
+    let seg_c = count_paths(graph, &x2, "out", &mut cache);
+    Ok(Answer::new(seg_a * seg_b * seg_c))
+}
+
+fn count_paths(
+    graph: &HashMap<String, Vec<String>>,
+    current: &str,
+    target: &str,
+    cache: &mut HashMap<(String, String), usize>,
+) -> usize {
+    let key = (current.to_string(), target.to_string());
+    if current == target {
+        cache.insert(key, 1);
+        return 1;
+    }
+    if let Some(paths) = cache.get(&key) {
+        return *paths;
+    }
+
+    let mut total = 0;
+    if let Some(neighbors) = graph.get(&key.0) {
+        for neighbor in neighbors {
+            total += count_paths(graph, neighbor, target, cache);
+        }
+    }
+    trace!(from = current, to = target, paths = total, "counted paths");
+    cache.insert(key, total);
+    total
+}
+
+/// One device per line, `name: output output ...`.
+pub fn load_graph(data_path: &Path) -> Result<HashMap<String, Vec<String>>> {
+    parse_file(data_path, |content| {
+        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+        for line in content.lines() {
+            let (from, to) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(content, line, "expected 'device: outputs'"))?;
+            let from = from.trim();
+            if from.is_empty() {
+                return Err(ParseError::at(content, line, "missing device name"));
+            }
+            let to = to.split_whitespace().map(str::to_string).collect();
+            if graph.insert(from.to_string(), to).is_some() {
+                return Err(ParseError::at(
+                    content,
+                    from,
+                    format!("device '{}' is listed twice", from),
+                ));
+            }
+        }
+        Ok(graph)
+    })
+}
+
+/// Devices in `layers` layers of `width`, each with 1 to `max_outputs` outputs in the next
+/// layer and the last layer connected to `out`, listed in random order. The device at the
+/// same position in the next layer is always an output, which connects `svr` (first
+/// layer) to `fft` and `dac` (later layers, in either order) and `out`. `you` is in the
+/// first layer as well.
+pub fn generate_graph(
+    rng: &mut Rng,
+    layers: usize,
+    width: usize,
+    max_outputs: usize,
+) -> Result<String> {
+    ensure!(layers >= 3, "layers must be at least 3");
+    ensure!(width >= 2, "width must be at least 2");
+    ensure!(
+        width * layers <= 10_000,
+        "at most 10000 devices have distinct names"
+    );
+    ensure!(max_outputs >= 1, "max_outputs must be at least 1");
+    // every path count is at most max_outputs ^ (layers - 1), part two multiplies three
+    // counts along one path
+    ensure!(
+        max_outputs.checked_pow(layers as u32 - 1).is_some(),
+        "the path counts would not fit, use fewer layers or outputs"
+    );
+    let special = ["you", "svr", "fft", "dac", "out"];
+    let mut used = special
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<HashSet<_>>();
+    let mut names = (0..layers)
+        .map(|_| {
+            (0..width)
+                .map(|_| {
+                    loop {
+                        let name = (0..3)
+                            .map(|_| char::from(b'a' + rng.range(0..=25) as u8))
+                            .collect::<String>();
+                        if used.insert(name.clone()) {
+                            break name;
+                        }
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let lane = rng.range(0..=width - 1);
+    let you = (lane + rng.range(1..=width - 1)) % width;
+    let first = rng.range(1..=layers - 2);
+    let second = rng.range(first + 1..=layers - 1);
+    let (a, b) = if rng.chance(50) {
+        ("fft", "dac")
+    } else {
+        ("dac", "fft")
+    };
+    names[0][lane] = "svr".to_string();
+    names[0][you] = "you".to_string();
+    names[first][lane] = a.to_string();
+    names[second][lane] = b.to_string();
+
+    let mut lines = Vec::new();
+    for layer in 0..layers {
+        for idx in 0..width {
+            let outputs = match names.get(layer + 1) {
+                Some(next) => {
+                    let mut outputs = vec![next[idx].as_str()];
+                    for _ in 1..rng.range(1..=max_outputs) {
+                        let output = rng.pick(next).as_str();
+                        if !outputs.contains(&output) {
+                            outputs.push(output);
+                        }
+                    }
+                    rng.shuffle(&mut outputs);
+                    outputs
+                }
+                None => vec!["out"],
+            };
+            lines.push(format!("{}: {}\n", names[layer][idx], outputs.join(" ")));
+        }
+    }
+    rng.shuffle(&mut lines);
+    Ok(lines.concat())
+}
@@ -0,0 +1,220 @@
+use anyhow::{Result, ensure};
+use std::path::Path;
+use tracing::{debug, trace};
+
+use crate::parse::{ParseError, parse_file, parse_token};
+use crate::rng::Rng;
+use crate::solution::{Answer, Context, Param, Solution};
+use crate::utils::Grid;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Farm;
+
+    const HAS_PART_TWO: bool = false;
+
+    fn parse(data_path: &Path) -> Result<Self::Input> {
+        let farm = load_farm(data_path)?;
+        debug!(
+            shapes = farm.shapes.len(),
+            regions = farm.regions.len(),
+            "parsed farm"
+        );
+        Ok(farm)
+    }
+
+    fn part_one(farm: &Self::Input, _ctx: &Context) -> Result<Answer> {
+        task_01(farm)
+    }
+
+    const HAS_GENERATOR: bool = true;
+
+    const KNOBS: &'static [Param] = &[
+        Param {
+            name: "shapes",
+            default: "6",
+            input_defaults: &[],
+        },
+        Param {
+            name: "size",
+            default: "3",
+            input_defaults: &[],
+        },
+        Param {
+            name: "regions",
+            default: "1000",
+            input_defaults: &[],
+        },
+        Param {
+            name: "max_side",
+            default: "50",
+            input_defaults: &[],
+        },
+    ];
+
+    fn generate(rng: &mut Rng, knobs: &Context) -> Result<String> {
+        generate_farm(
+            rng,
+            knobs.param("shapes")?,
+            knobs.param("size")?,
+            knobs.param("regions")?,
+            knobs.param("max_side")?,
+        )
+    }
+}
+
+struct Region {
+    width: usize,
+    height: usize,
+    /// Number of presents of every shape to fit under the tree.
+    counts: Vec<usize>,
+}
+
+pub struct Farm {
+    /// Present shapes, `true` where the present fills the cell.
+    shapes: Vec<Grid<bool>>,
+    regions: Vec<Region>,
+}
+
+pub fn task_01(farm: &Farm) -> Result<Answer> {
+    let presents_area = farm
+        .shapes
+        .iter()
+        .map(|shape| shape.iter().filter(|(_, filled)| **filled).count())
+        .collect::<Vec<usize>>();
+    let mut total = 0;
+    for (idx, region) in farm.regions.iter().enumerate() {
+        let total_area = region.width * region.height;
+        let present_area = region
+            .counts
+            .iter()
+            .zip(&presents_area)
+            .map(|(present_count, present_area)| present_count * present_area)
+            .sum::<usize>();
+        let fits = present_area <= total_area;
+        trace!(
+            region = idx,
+            total_area, present_area, fits, "checked region"
+        );
+        total += if fits { 1 } else { 0 };
+    }
+    Ok(Answer::new(total))
+}
+
+/// Blocks of numbered present shapes separated by blank lines, followed by one region per
+/// line, `WxH: count count ...` with a count for every shape.
+pub fn load_farm(data_path: &Path) -> Result<Farm> {
+    parse_file(data_path, |content| {
+        let blocks = content.split("\n\n").collect::<Vec<_>>();
+        let (regions, shapes) = blocks.split_last().expect("split yields a block");
+        let shapes = shapes
+            .iter()
+            .enumerate()
+            .map(|(idx, block)| parse_shape(content, block, idx))
+            .collect::<Result<Vec<_>, _>>()?;
+        let regions = regions
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_region(content, line, shapes.len()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Farm { shapes, regions })
+    })
+}
+
+fn parse_shape(content: &str, block: &str, idx: usize) -> Result<Grid<bool>, ParseError> {
+    let header = block.lines().next().unwrap_or(block);
+    let number = header
+        .strip_suffix(':')
+        .ok_or_else(|| ParseError::at(content, header, "expected a shape header 'N:'"))?;
+    if parse_token::<usize>(content, number)? != idx {
+        return Err(ParseError::at(
+            content,
+            number,
+            format!("expected shape {}", idx),
+        ));
+    }
+    let rows = block.strip_prefix(header).unwrap_or(block);
+    Grid::parse_in(
+        content,
+        rows.strip_prefix('\n').unwrap_or(rows),
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+    )
+}
+
+fn parse_region(content: &str, line: &str, shapes: usize) -> Result<Region, ParseError> {
+    let (size, counts) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::at(content, line, "expected 'WxH: counts'"))?;
+    let (width, height) = size
+        .split_once('x')
+        .ok_or_else(|| ParseError::at(content, size, "expected a size 'WxH'"))?;
+    let counts = counts
+        .split_whitespace()
+        .map(|count| parse_token(content, count))
+        .collect::<Result<Vec<usize>, _>>()?;
+    if counts.len() != shapes {
+        return Err(ParseError::at(
+            content,
+            line,
+            format!("expected {} counts, found {}", shapes, counts.len()),
+        ));
+    }
+    Ok(Region {
+        width: parse_token(content, width)?,
+        height: parse_token(content, height)?,
+        counts,
+    })
+}
+
+/// `shapes` random `size`×`size` present shapes and `regions` regions with sides from
+/// `size` to `max_side`. The presents of a region add up to between half and six fifths
+/// of its area, so some regions are too small for them.
+pub fn generate_farm(
+    rng: &mut Rng,
+    shapes: usize,
+    size: usize,
+    regions: usize,
+    max_side: usize,
+) -> Result<String> {
+    ensure!(shapes >= 1, "shapes must be at least 1");
+    ensure!(size >= 1, "size must be at least 1");
+    ensure!(max_side >= size, "max_side must be at least size");
+    let mut content = String::new();
+    let mut areas = Vec::new();
+    for idx in 0..shapes {
+        // the first cell is always filled so no shape is empty
+        let cells = (0..size * size)
+            .map(|cell| cell == 0 || rng.chance(70))
+            .collect::<Vec<_>>();
+        areas.push(cells.iter().filter(|&&filled| filled).count());
+        content += &format!("{}:\n", idx);
+        for row in cells.chunks(size) {
+            content += &row
+                .iter()
+                .map(|&filled| if filled { '#' } else { '.' })
+                .collect::<String>();
+            content.push('\n');
+        }
+        content.push('\n');
+    }
+    for _ in 0..regions {
+        let width = rng.range(size..=max_side);
+        let height = rng.range(size..=max_side);
+        let target = width * height * rng.range(50..=120) / 100;
+        let mut counts = vec![0; shapes];
+        let mut area = 0;
+        while area < target {
+            let shape = rng.range(0..=shapes - 1);
+            counts[shape] += 1;
+            area += areas[shape];
+        }
+        let counts = counts.iter().map(usize::to_string).collect::<Vec<_>>();
+        content += &format!("{}x{}: {}\n", width, height, counts.join(" "));
+    }
+    Ok(content)
+}
@@ -0,0 +1,31 @@
+//! Solutions of Advent of Code 2025.
+
+use crate::solution::Day;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_01::Day01>(2025, "day_01"),
+    Day::new::<day_02::Day02>(2025, "day_02"),
+    Day::new::<day_03::Day03>(2025, "day_03"),
+    Day::new::<day_04::Day04>(2025, "day_04"),
+    Day::new::<day_05::Day05>(2025, "day_05"),
+    Day::new::<day_06::Day06>(2025, "day_06"),
+    Day::new::<day_07::Day07>(2025, "day_07"),
+    Day::new::<day_08::Day08>(2025, "day_08"),
+    Day::new::<day_09::Day09>(2025, "day_09"),
+    Day::new::<day_10::Day10>(2025, "day_10"),
+    Day::new::<day_11::Day11>(2025, "day_11"),
+    Day::new::<day_12::Day12>(2025, "day_12"),
+];
@@ -0,0 +1,67 @@
+//! Checks how the binary reads its input arguments.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::{env, fs};
+
+const BIN: &str = env!("CARGO_BIN_EXE_advent-of-code-2025");
+
+fn stdout(args: &[&str]) -> String {
+    let output = Command::new(BIN).args(args).output().unwrap();
+    assert!(output.status.success(), "{:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_name_can_follow_a_space() {
+    let attached = stdout(&["-s", "--test=02", "day_11"]);
+    assert_eq!(attached, "day_11: 2\n");
+    assert_eq!(stdout(&["-s", "--test-name", "02", "day_11"]), attached);
+    assert_eq!(stdout(&["--test-name", "02", "-s", "day_11"]), attached);
+}
+
+/// Temporary files holding stdin, which get random names.
+fn stdin_files() -> Vec<PathBuf> {
+    let mut files = fs::read_dir(env::temp_dir())
+        .unwrap()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("aoc-stdin-"))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+#[test]
+fn stdin_file_is_removed() {
+    let before = stdin_files();
+    let mut child = Command::new(BIN)
+        .args(["-i", "-", "day_01"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let input = fs::read_to_string("data/2025/day_01/test.txt").unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .starts_with("day_01: 3")
+    );
+    assert_eq!(
+        stdin_files(),
+        before,
+        "stdin was left behind in a temporary file"
+    );
+}
@@ -0,0 +1,30 @@
+//! Checks that every input generator is deterministic and produces inputs its day parses.
+
+use std::{env, fs};
+
+use advent_of_code_2025::registry::YEARS;
+
+#[test]
+fn generated_inputs_parse() {
+    for year in YEARS {
+        for day in year.days.iter().filter(|day| day.has_generator) {
+            for seed in 0..3 {
+                let content = day.generate(seed, None, &[]).unwrap();
+                assert_eq!(content, day.generate(seed, None, &[]).unwrap());
+                let path = env::temp_dir().join(format!(
+                    "aoc-generate-{}-{}-{}-{}.txt",
+                    std::process::id(),
+                    year.year,
+                    day.name,
+                    seed
+                ));
+                fs::write(&path, content).unwrap();
+                let parsed = day.parse(&path);
+                fs::remove_file(&path).unwrap();
+                if let Err(err) = parsed {
+                    panic!("{} {} seed {}: {:#}", year.year, day.name, seed, err);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Checks that tools outside the crate can drive the solvers directly.
+
+use advent_of_code_2025::y2025::day_10::{Instruction, resolve_joltage_instructions};
+
+#[test]
+fn joltage_solver_is_public() {
+    // first machine of the day 10 example
+    let instruction = Instruction::new(
+        vec![false, true, true, false],
+        vec![
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ],
+        vec![3, 5, 4, 7],
+    )
+    .unwrap();
+    assert_eq!(instruction.lights(), &[false, true, true, false]);
+    assert_eq!(instruction.buttons().nth(1), Some(&[1, 3][..]));
+    assert_eq!(resolve_joltage_instructions(&instruction).unwrap(), 10);
+    assert!(Instruction::new(vec![true], vec![vec![1]], vec![1]).is_err());
+}
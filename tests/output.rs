@@ -0,0 +1,32 @@
+//! Checks that machine-readable output formats keep stdout free of anything else, so it
+//! can be piped into other tools.
+
+use std::process::Command;
+
+fn stdout(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2025"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn json_output_is_only_json() {
+    // day 10 part two runs lp_solve, which logs to stdout unless silenced
+    let stdout = stdout(&["-s", "-t", "--format", "json", "day_10"]);
+    let result = serde_json::from_str::<serde_json::Value>(&stdout)
+        .unwrap_or_else(|err| panic!("not a single JSON value ({}):\n{}", err, stdout));
+    assert_eq!(result["status"], "ok");
+    assert_eq!(result["answer"], 33);
+}
+
+#[test]
+fn csv_output_is_only_csv() {
+    let stdout = stdout(&["-s", "-t", "--format", "csv", "day_10"]);
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2, "expected a header and one row:\n{}", stdout);
+    assert!(lines[0].starts_with("day,part,status,answer"));
+    assert!(lines[1].starts_with("day_10,2,ok,33,"));
+}
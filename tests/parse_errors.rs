@@ -0,0 +1,30 @@
+//! Checks that every day reports malformed input as a `ParseError` instead of panicking.
+
+use std::{env, fs};
+
+use advent_of_code_2025::parse::ParseError;
+use advent_of_code_2025::registry::YEARS;
+
+#[test]
+fn malformed_inputs_are_parse_errors() {
+    for year in YEARS {
+        for day in year.days {
+            let path = env::temp_dir().join(format!(
+                "aoc-parse-errors-{}-{}-{}.txt",
+                std::process::id(),
+                year.year,
+                day.name
+            ));
+            fs::write(&path, "?\n").unwrap();
+            let parsed = day.parse(&path);
+            fs::remove_file(&path).unwrap();
+            let err = parsed
+                .err()
+                .unwrap_or_else(|| panic!("{} accepted '?'", day.name));
+            let err = err
+                .downcast::<ParseError>()
+                .unwrap_or_else(|err| panic!("{}: not a parse error: {:#}", day.name, err));
+            assert_eq!((err.line, err.column), (1, 1), "{}", day.name);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Runs every day on its test inputs and compares the answers with
+//! `data/<year>/<day>/answers.toml`. The tests themselves are generated by `build.rs`.
+//! Days with a reference implementation also have the expected answer checked against
+//! it, so a manifest cannot just record what a buggy solution printed.
+
+use advent_of_code_2025::answers::Answers;
+use advent_of_code_2025::input::InputSource;
+use advent_of_code_2025::registry;
+use advent_of_code_2025::runner;
+use advent_of_code_2025::solution::{Context, Part};
+
+fn check(year: u16, day: &str, part: u8, input: &str) {
+    let day = registry::find(year, day)
+        .unwrap_or_else(|| panic!("{} of {} is not registered", day, year));
+    let part = match part {
+        1 => Part::One,
+        _ => Part::Two,
+    };
+    let expected = Answers::load(day)
+        .unwrap()
+        .expected(input, part)
+        .unwrap()
+        .expect("the test is only generated for inputs with an answer");
+    let source = InputSource::Test(Some(input.to_string()));
+    if day.has_reference {
+        let ctx = Context::new(day.params, Some(input), &[]).unwrap();
+        let parsed = day.parse(&source.resolve(day, part).unwrap()).unwrap();
+        let reference = day.solve_reference(parsed.as_ref(), part, &ctx).unwrap();
+        assert_eq!(
+            reference.value.to_string(),
+            expected,
+            "the reference implementation disagrees with answers.toml"
+        );
+    }
+    let answer = runner::run(day, part, &source, &[]).answer.unwrap();
+    assert_eq!(answer.value.to_string(), expected);
+}
+
+include!(concat!(env!("OUT_DIR"), "/regression_tests.rs"));